@@ -0,0 +1,216 @@
+use bevy::utils::HashMap;
+use flate2::read::GzDecoder;
+use std::{
+    io::{Cursor, Read},
+    path::{Component, Path, PathBuf},
+};
+use zip::ZipArchive;
+
+use crate::{loader::check_limit, GltfError, GltfLimit, GltfLimits};
+
+/// The decompressed contents of a zip archive or gzip-compressed glTF file.
+pub(crate) struct Archive {
+    /// The glTF or GLB file.
+    pub(crate) gltf: Vec<u8>,
+    /// The path of the glTF file within the archive.
+    gltf_path: PathBuf,
+    /// The other files in the archive by path.
+    files: HashMap<PathBuf, Vec<u8>>,
+}
+
+impl Archive {
+    /// Decompresses the file at `path` if its extension is that of an archive, stopping once the
+    /// decompressed files exceed the archive limit.
+    pub(crate) fn unpack(
+        bytes: &[u8],
+        path: &Path,
+        limits: &GltfLimits,
+    ) -> Result<Option<Archive>, GltfError> {
+        let file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default()
+            .to_lowercase();
+        if file_name.ends_with(".zip") {
+            Archive::unpack_zip(bytes, limits).map(Some)
+        } else if file_name.ends_with(".gz") {
+            let gltf = read_limited(GzDecoder::new(bytes), limits.max_archive_bytes, 0)?;
+            Ok(Some(Archive {
+                gltf,
+                gltf_path: PathBuf::new(),
+                files: HashMap::default(),
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Extracts all files from a zip archive, taking the glTF file closest to its root.
+    fn unpack_zip(bytes: &[u8], limits: &GltfLimits) -> Result<Archive, GltfError> {
+        let mut zip = ZipArchive::new(Cursor::new(bytes))?;
+        let mut files = HashMap::default();
+        let mut total = 0usize;
+        for index in 0..zip.len() {
+            let mut file = zip.by_index(index)?;
+            let Some(path) = file.enclosed_name().map(Path::to_path_buf) else {
+                continue;
+            };
+            if file.is_file() {
+                // The declared size is checked before decompressing, but can't be trusted
+                check_limit(
+                    GltfLimit::ArchiveBytes,
+                    limits.max_archive_bytes,
+                    total.saturating_add(file.size().try_into().unwrap_or(usize::MAX)),
+                )?;
+                let contents = read_limited(&mut file, limits.max_archive_bytes, total)?;
+                total += contents.len();
+                files.insert(path, contents);
+            }
+        }
+
+        let gltf_path = files
+            .keys()
+            .filter(|path| {
+                path.extension()
+                    .and_then(|extension| extension.to_str())
+                    .map(|extension| ["gltf", "glb"].contains(&&*extension.to_lowercase()))
+                    .unwrap_or(false)
+            })
+            .min_by_key(|path| (path.components().count(), path.to_path_buf()))
+            .cloned()
+            .ok_or(GltfError::MissingArchiveGltf)?;
+        let gltf = files.remove(&gltf_path).unwrap_or_default();
+        Ok(Archive {
+            gltf,
+            gltf_path,
+            files,
+        })
+    }
+
+    /// Returns the contents of the file which `uri` refers to relative to the glTF file.
+    pub(crate) fn read(&self, uri: &str) -> Option<&[u8]> {
+        let mut path = PathBuf::new();
+        for component in self.gltf_path.parent()?.join(uri).components() {
+            match component {
+                Component::Normal(name) => path.push(name),
+                Component::ParentDir => {
+                    path.pop();
+                }
+                _ => {}
+            }
+        }
+        self.files.get(&path).map(Vec::as_slice)
+    }
+}
+
+/// Reads all of `reader`, failing if that takes the `read` bytes already decompressed over `max`.
+fn read_limited(reader: impl Read, max: Option<usize>, read: usize) -> Result<Vec<u8>, GltfError> {
+    // Reads a byte more than is allowed to tell whether the limit is exceeded
+    let allowed = max.map_or(u64::MAX, |max| max.saturating_sub(read) as u64 + 1);
+    let mut contents = Vec::new();
+    reader.take(allowed).read_to_end(&mut contents)?;
+    check_limit(
+        GltfLimit::ArchiveBytes,
+        max,
+        read.saturating_add(contents.len()),
+    )?;
+    Ok(contents)
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::{Cursor, Write};
+    use std::path::Path;
+
+    use flate2::{write::GzEncoder, Compression};
+    use zip::{write::FileOptions, ZipWriter};
+
+    use super::Archive;
+    use crate::{GltfError, GltfLimit, GltfLimits};
+
+    fn zip(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, contents) in files {
+            writer.start_file(*name, FileOptions::default()).unwrap();
+            writer.write_all(contents).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    fn gzip(contents: &[u8]) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(contents).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn unpack_zip() {
+        let bytes = zip(&[
+            ("level/extra/other.gltf", b"other"),
+            ("level/scene.gltf", b"scene"),
+            ("level/scene.bin", b"buffer"),
+            ("textures/a.png", b"image"),
+        ]);
+        let archive = Archive::unpack(
+            &bytes,
+            Path::new("levels/level.gltf.zip"),
+            &GltfLimits::default(),
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(archive.gltf, b"scene");
+        assert_eq!(archive.read("scene.bin"), Some(&b"buffer"[..]));
+        assert_eq!(archive.read("./../textures/a.png"), Some(&b"image"[..]));
+        assert_eq!(archive.read("missing.png"), None);
+    }
+
+    #[test]
+    fn unpack_gzip() {
+        let bytes = gzip(b"glTF");
+        let archive = Archive::unpack(&bytes, Path::new("model.glb.gz"), &GltfLimits::default())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(archive.gltf, b"glTF");
+    }
+
+    #[test]
+    fn unpack_uncompressed() {
+        assert!(
+            Archive::unpack(b"{}", Path::new("model.gltf"), &GltfLimits::default())
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn unpack_limited() {
+        let limits = GltfLimits {
+            max_archive_bytes: Some(1000),
+            ..Default::default()
+        };
+        let exceeded = |result| {
+            matches!(
+                result,
+                Err(GltfError::LimitExceeded {
+                    limit: GltfLimit::ArchiveBytes,
+                    max: 1000
+                })
+            )
+        };
+
+        let zeros = [0; 600];
+        let bytes = zip(&[("scene.gltf", &zeros), ("scene.bin", &zeros)]);
+        let result = Archive::unpack(&bytes, Path::new("level.gltf.zip"), &limits);
+        assert!(exceeded(result.map(|_| ())));
+        let bytes = zip(&[("scene.gltf", &zeros[..500]), ("scene.bin", &zeros[..500])]);
+        assert!(Archive::unpack(&bytes, Path::new("level.gltf.zip"), &limits).is_ok());
+
+        let bytes = gzip(&[0; 1001]);
+        let result = Archive::unpack(&bytes, Path::new("model.glb.gz"), &limits);
+        assert!(exceeded(result.map(|_| ())));
+        let bytes = gzip(&[0; 1000]);
+        assert!(Archive::unpack(&bytes, Path::new("model.glb.gz"), &limits).is_ok());
+    }
+}
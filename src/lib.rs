@@ -2,25 +2,57 @@
 use bevy::animation::AnimationClip;
 use bevy::utils::HashMap;
 
+#[cfg(feature = "archive")]
+mod archive;
 mod loader;
+mod mipmap;
+mod post_process;
+mod resolver;
+mod skinning;
 pub use loader::*;
+pub use post_process::*;
+pub use resolver::*;
 
 use bevy::app::prelude::*;
+#[cfg(feature = "serde")]
+use bevy::app::AppTypeRegistry;
 use bevy::asset::{AddAsset, Handle};
+#[cfg(feature = "serde")]
+use bevy::ecs::world::EntityMut;
 use bevy::ecs::{prelude::Component, reflect::ReflectComponent};
 use bevy::pbr::StandardMaterial;
-use bevy::reflect::{Reflect, TypeUuid};
+use bevy::prelude::{Deref, DerefMut};
+use bevy::reflect::{FromReflect, Reflect, TypeUuid};
 use bevy::render::{
     mesh::{Mesh, MeshVertexAttribute},
+    render_resource::SamplerDescriptor,
     renderer::RenderDevice,
-    texture::CompressedImageFormats,
+    texture::{CompressedImageFormats, Image},
 };
 use bevy::scene::Scene;
+#[cfg(feature = "serde")]
+use std::sync::OnceLock;
+use std::{path::PathBuf, sync::Arc};
 
 /// Adds support for glTF file loading to the app.
 #[derive(Default)]
 pub struct GltfPlugin {
     custom_vertex_attributes: HashMap<String, MeshVertexAttribute>,
+    generate_mipmaps: bool,
+    texture_size_limits: TextureSizeLimits,
+    sampler_policy: GltfSamplerPolicy,
+    sampler_override: Option<Arc<SamplerOverride>>,
+    texture_error_policy: TextureErrorPolicy,
+    uri_resolver: Option<Arc<dyn UriResolver>>,
+    asset_kinds: GltfAssetKinds,
+    strict: bool,
+    limits: GltfLimits,
+    joint_influences: JointInfluences,
+    #[cfg(feature = "serde")]
+    extras_components_key: Option<String>,
+    #[cfg(feature = "serde")]
+    extras_handlers: HashMap<String, Arc<ExtrasHandler>>,
+    scene_post_processors: Vec<Arc<dyn ScenePostProcessor>>,
 }
 
 impl GltfPlugin {
@@ -33,6 +65,123 @@ impl GltfPlugin {
             .insert(name.to_string(), attribute);
         self
     }
+
+    /// Generates mipmaps for textures which don't have them when they are loaded. Color textures
+    /// are filtered in linear space and normal maps are renormalized. Textures in formats which
+    /// can't be filtered, such as compressed formats and 16-bit floats, raise a
+    /// [`GltfWarning::MipmapsNotGenerated`].
+    pub fn generate_mipmaps(mut self, enabled: bool) -> Self {
+        self.generate_mipmaps = enabled;
+        self
+    }
+
+    /// Downscales textures whose width or height exceeds `max_dimension` when they are loaded,
+    /// halving their size until they fit. Textures with mipmaps have their largest levels
+    /// dropped instead. Textures which can't be downscaled, such as compressed textures without
+    /// mipmaps, raise a [`GltfWarning::ImageNotDownscaled`].
+    pub fn max_texture_dimension(mut self, max_dimension: u32) -> Self {
+        self.texture_size_limits.max_dimension = Some(max_dimension);
+        self
+    }
+
+    /// Overrides the maximum texture dimension for textures used in the given `role`. The most
+    /// restrictive limit applies to textures used in several roles.
+    pub fn max_texture_dimension_for(mut self, role: TextureRole, max_dimension: u32) -> Self {
+        self.texture_size_limits
+            .max_dimension_by_role
+            .insert(role, max_dimension);
+        self
+    }
+
+    /// Sets the adjustments applied to the samplers of all loaded textures.
+    pub fn sampler_policy(mut self, policy: GltfSamplerPolicy) -> Self {
+        self.sampler_policy = policy;
+        self
+    }
+
+    /// Sets a function to adjust the sampler of each loaded texture after the
+    /// [`GltfSamplerPolicy`] has been applied.
+    pub fn sampler_override(
+        mut self,
+        sampler_override: impl Fn(&gltf::texture::Sampler, &[TextureRole], &mut SamplerDescriptor<'static>)
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        self.sampler_override = Some(Arc::new(sampler_override));
+        self
+    }
+
+    /// Sets what to do when an image used by a texture can't be loaded.
+    pub fn texture_error_policy(mut self, policy: TextureErrorPolicy) -> Self {
+        self.texture_error_policy = policy;
+        self
+    }
+
+    /// Sets the [`UriResolver`] used to locate external buffers and images.
+    pub fn uri_resolver(mut self, resolver: impl UriResolver) -> Self {
+        self.uri_resolver = Some(Arc::new(resolver));
+        self
+    }
+
+    /// Sets the kinds of asset loaded from glTF files, for example [`GltfAssetKinds::GEOMETRY`]
+    /// to load only meshes and nodes.
+    pub fn asset_kinds(mut self, asset_kinds: GltfAssetKinds) -> Self {
+        self.asset_kinds = asset_kinds;
+        self
+    }
+
+    /// Fails to load glTF files with the [`GltfError`] corresponding to any [`GltfWarning`] which
+    /// would leave out or alter part of the file, regardless of the [`TextureErrorPolicy`].
+    /// [`GltfWarning::TextureColorSpaceConflict`] and [`GltfWarning::MipmapsNotGenerated`] are
+    /// still raised as warnings.
+    pub fn strict(mut self, enabled: bool) -> Self {
+        self.strict = enabled;
+        self
+    }
+
+    /// Sets limits on the resources used by glTF files, for loading files from untrusted
+    /// sources.
+    pub fn limits(mut self, limits: GltfLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Sets how many joints may influence each vertex of a skinned mesh.
+    pub fn joint_influences(mut self, influences: JointInfluences) -> Self {
+        self.joint_influences = influences;
+        self
+    }
+
+    /// Inserts reflected components described by the `key` of each node's extras into the
+    /// node's entity in scenes. The key holds a map from the full or short names of component
+    /// types registered in the [`AppTypeRegistry`] to their values in the form used by scene
+    /// files, for example `{"bevy_components": {"Health": {"max": 10}}}`.
+    #[cfg(feature = "serde")]
+    pub fn extras_components_key(mut self, key: &str) -> Self {
+        self.extras_components_key = Some(key.to_string());
+        self
+    }
+
+    /// Registers a function to insert components into the entities in scenes spawned from
+    /// nodes, primitives and lights whose extras have the given `property`, given its value.
+    #[cfg(feature = "serde")]
+    pub fn add_extras_handler(
+        mut self,
+        property: &str,
+        handler: impl Fn(&serde_json::Value, &mut EntityMut) + Send + Sync + 'static,
+    ) -> Self {
+        self.extras_handlers
+            .insert(property.to_string(), Arc::new(handler));
+        self
+    }
+
+    /// Adds a [`ScenePostProcessor`] to modify the world of each scene before it is made into a
+    /// [`Scene`]. Post-processors run in the order they were added.
+    pub fn add_scene_post_processor(mut self, processor: impl ScenePostProcessor) -> Self {
+        self.scene_post_processors.push(Arc::new(processor));
+        self
+    }
 }
 
 impl Plugin for GltfPlugin {
@@ -45,12 +194,38 @@ impl Plugin for GltfPlugin {
         app.add_asset_loader::<GltfLoader>(GltfLoader {
             supported_compressed_formats,
             custom_vertex_attributes: self.custom_vertex_attributes.clone(),
+            generate_mipmaps: self.generate_mipmaps,
+            texture_size_limits: self.texture_size_limits.clone(),
+            sampler_policy: self.sampler_policy.clone(),
+            sampler_override: self.sampler_override.clone(),
+            texture_error_policy: self.texture_error_policy,
+            uri_resolver: self.uri_resolver.clone(),
+            asset_kinds: self.asset_kinds,
+            strict: self.strict,
+            limits: self.limits.clone(),
+            joint_influences: self.joint_influences,
+            #[cfg(feature = "serde")]
+            extras_components_key: self.extras_components_key.clone(),
+            #[cfg(feature = "serde")]
+            extras_handlers: self.extras_handlers.clone(),
+            scene_post_processors: self.scene_post_processors.clone(),
+            #[cfg(feature = "serde")]
+            type_registry: app
+                .world
+                .get_resource::<AppTypeRegistry>()
+                .cloned()
+                .unwrap_or_default(),
         })
         .register_type::<GltfExtras>()
+        .register_type::<GltfSceneExtras>()
+        .register_type::<GltfMeshExtras>()
+        .register_type::<GltfMaterialExtras>()
+        .register_type::<GltfAssetInfo>()
         .add_asset::<Gltf>()
         .add_asset::<GltfNode>()
         .add_asset::<GltfPrimitive>()
-        .add_asset::<GltfMesh>();
+        .add_asset::<GltfMesh>()
+        .add_asset::<GltfTexture>();
     }
 }
 
@@ -71,6 +246,37 @@ pub struct Gltf {
     pub animations: Vec<Handle<AnimationClip>>,
     #[cfg(feature = "bevy_animation")]
     pub named_animations: HashMap<String, Handle<AnimationClip>>,
+    /// Problems encountered while loading the file.
+    pub warnings: Vec<GltfWarning>,
+    /// Asset paths of the external buffers and images read while loading the file. The file is
+    /// reloaded when any of them change.
+    pub dependencies: Vec<PathBuf>,
+    /// Metadata about the file as a whole.
+    pub info: GltfAssetInfo,
+    /// The extras of each scene in the file, by index, whether or not scenes were loaded.
+    pub scene_extras: Vec<Option<GltfExtras>>,
+}
+
+/// Metadata about a glTF file, on [`Gltf`] and the root entity of each of its scenes.
+#[derive(Clone, Debug, Reflect, Default, Component)]
+#[reflect(Component)]
+pub struct GltfAssetInfo {
+    /// The glTF version the file targets.
+    pub version: String,
+    /// The minimum glTF version needed to load the file.
+    pub min_version: Option<String>,
+    /// The tool which generated the file.
+    pub generator: Option<String>,
+    /// A copyright message crediting the creator of the content.
+    pub copyright: Option<String>,
+    /// The names of the extensions used somewhere in the file.
+    pub extensions_used: Vec<String>,
+    /// The names of the extensions needed to load the file.
+    pub extensions_required: Vec<String>,
+    /// The extras of the file's `asset` property.
+    pub asset_extras: Option<GltfExtras>,
+    /// The extras of the file's top level.
+    pub extras: Option<GltfExtras>,
 }
 
 /// A glTF node with all of its child nodes, its [`GltfMesh`],
@@ -93,6 +299,25 @@ pub struct GltfMesh {
     pub extras: Option<GltfExtras>,
 }
 
+/// A glTF texture, which pairs an [`Image`] with a sampler. Each source image is decoded once,
+/// and textures using it in the same way with the same sampler share one image asset, labelled
+/// `Image{index}`. Bevy keeps the sampler in the image, so textures with different samplers have
+/// their own copy of the image.
+#[derive(Debug, Clone, TypeUuid)]
+#[uuid = "3d7bd5a6-f3db-4e1f-9671-abc865dbbff8"]
+pub struct GltfTexture {
+    /// The image, in sRGB if the texture is used as color data and linear otherwise.
+    pub image: Handle<Image>,
+    /// The image in linear color space, if the texture is used as both color and non-color data
+    /// other than a normal map.
+    pub linear_image: Option<Handle<Image>>,
+    /// The image filtered as a normal map, if the texture is used as both a normal map and in
+    /// other roles.
+    pub normal_map_image: Option<Handle<Image>>,
+    /// The texture's sampler, which its images are sampled with.
+    pub sampler: SamplerDescriptor<'static>,
+}
+
 /// Part of a [`GltfMesh`] that consists of a [`Mesh`], an optional [`StandardMaterial`] and [`GltfExtras`].
 #[derive(Debug, Clone, TypeUuid)]
 #[uuid = "cbfca302-82fd-41cb-af77-cab6b3d50af1"]
@@ -103,8 +328,103 @@ pub struct GltfPrimitive {
     pub material_extras: Option<GltfExtras>,
 }
 
-#[derive(Clone, Debug, Reflect, Default, Component)]
+/// Additional data attached to a glTF node, mesh, primitive, material, light or scene, as JSON.
+#[derive(Clone, Debug, Reflect, FromReflect, Default, Component)]
 #[reflect(Component)]
 pub struct GltfExtras {
-    pub value: String,
+    value: String,
+    /// `value` parsed as JSON, or `None` if it isn't valid JSON.
+    #[cfg(feature = "serde")]
+    #[reflect(ignore)]
+    json: OnceLock<Option<serde_json::Value>>,
+}
+
+impl GltfExtras {
+    pub fn new(value: String) -> Self {
+        GltfExtras {
+            value,
+            #[cfg(feature = "serde")]
+            json: OnceLock::new(),
+        }
+    }
+
+    /// Returns the JSON of the extras.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Replaces the JSON of the extras.
+    pub fn set_value(&mut self, value: String) {
+        self.value = value;
+        #[cfg(feature = "serde")]
+        {
+            self.json = OnceLock::new();
+        }
+    }
+}
+
+/// The extras of a glTF scene, on the root entity of its [`Scene`].
+#[derive(Clone, Debug, Reflect, Default, Component, Deref, DerefMut)]
+#[reflect(Component)]
+pub struct GltfSceneExtras(pub GltfExtras);
+
+/// The extras of a glTF mesh, on the entities of each of its primitives in scenes.
+#[derive(Clone, Debug, Reflect, Default, Component, Deref, DerefMut)]
+#[reflect(Component)]
+pub struct GltfMeshExtras(pub GltfExtras);
+
+/// The extras of the material of a glTF primitive, on the primitive's entity in scenes.
+#[derive(Clone, Debug, Reflect, Default, Component, Deref, DerefMut)]
+#[reflect(Component)]
+pub struct GltfMaterialExtras(pub GltfExtras);
+
+#[cfg(feature = "serde")]
+impl GltfExtras {
+    /// Returns the extras parsed as JSON, or `None` if they aren't valid JSON. They are parsed
+    /// the first time this is called after they're set.
+    pub fn json(&self) -> Option<&serde_json::Value> {
+        self.json
+            .get_or_init(|| serde_json::from_str(&self.value).ok())
+            .as_ref()
+    }
+
+    /// Looks up a value in the extras by a JSON pointer such as `/spawn/team`.
+    pub fn pointer(&self, pointer: &str) -> Option<&serde_json::Value> {
+        self.json()?.pointer(pointer)
+    }
+
+    /// Deserializes the extras into a serde type.
+    pub fn deserialize<T: serde::de::DeserializeOwned>(&self) -> serde_json::Result<T> {
+        serde_json::from_str(&self.value)
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod test {
+    use bevy::utils::HashMap;
+
+    use super::GltfExtras;
+
+    #[test]
+    fn extras_parsed_access() {
+        let extras = GltfExtras::new(r#"{"spawn": {"team": "red", "delay": [0.5, 2]}}"#.into());
+
+        assert_eq!(extras.pointer("/spawn/team"), Some(&"red".into()));
+        assert_eq!(extras.pointer("/spawn/delay/1"), Some(&2.into()));
+        assert_eq!(extras.pointer("/spawn/missing"), None);
+        assert!(std::ptr::eq(extras.json().unwrap(), extras.json().unwrap()));
+
+        let spawn = extras
+            .deserialize::<HashMap<String, HashMap<String, serde_json::Value>>>()
+            .unwrap();
+        assert_eq!(spawn["spawn"]["team"], "red");
+        assert!(extras.deserialize::<Vec<u32>>().is_err());
+
+        let mut invalid = GltfExtras::new("{".into());
+        assert_eq!(invalid.json(), None);
+        assert_eq!(invalid.pointer(""), None);
+
+        invalid.set_value("[1]".into());
+        assert_eq!(invalid.pointer("/0"), Some(&1.into()));
+    }
 }
@@ -1,9 +1,15 @@
 use anyhow::Result;
+#[cfg(feature = "serde")]
+use bevy::app::AppTypeRegistry;
 use bevy::asset::{
     AssetIoError, AssetLoader, AssetPath, BoxedFuture, Handle, LoadContext, LoadedAsset,
 };
 use bevy::core::Name;
 use bevy::core_pipeline::prelude::Camera3dBundle;
+#[cfg(feature = "serde")]
+use bevy::ecs::reflect::ReflectComponent;
+#[cfg(feature = "serde")]
+use bevy::ecs::world::EntityMut;
 use bevy::ecs::{entity::Entity, world::World};
 use bevy::hierarchy::{BuildWorldChildren, WorldChildBuilder};
 use bevy::log::warn;
@@ -22,7 +28,8 @@ use bevy::render::{
     prelude::SpatialBundle,
     primitives::Aabb,
     render_resource::{
-        AddressMode, Face, FilterMode, PrimitiveTopology, SamplerDescriptor, VertexFormat,
+        AddressMode, Extent3d, Face, FilterMode, PrimitiveTopology, SamplerDescriptor,
+        TextureDimension, TextureFormat, VertexFormat,
     },
     texture::{CompressedImageFormats, Image, ImageSampler, ImageType, TextureError},
 };
@@ -35,16 +42,31 @@ use bevy::utils::{HashMap, HashSet};
 use gltf::{
     accessor::{DataType, Dimensions},
     mesh::{
-        util::{ReadColors, ReadIndices, ReadJoints, ReadTexCoords},
+        util::{ReadColors, ReadIndices, ReadJoints, ReadTexCoords, ReadWeights},
         Mode,
     },
     texture::{MagFilter, MinFilter, WrappingMode},
     Material, Node, Primitive,
 };
-use std::{collections::VecDeque, path::Path};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, VecDeque},
+    fmt, io,
+    num::NonZeroU8,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
 use thiserror::Error;
 
-use crate::{Gltf, GltfExtras, GltfNode};
+#[cfg(feature = "archive")]
+use crate::archive::Archive;
+use crate::{
+    mipmap::{generate_mipmaps, limit_size},
+    resolver::default_uri_path,
+    skinning::{partition_skinned_mesh, MAX_JOINTS},
+    Gltf, GltfAssetInfo, GltfExtras, GltfMaterialExtras, GltfMeshExtras, GltfNode, GltfSceneExtras,
+    GltfTexture, ResolvedUri, ScenePostProcessor, UriResolver, UriRole,
+};
 
 /// An error that occurs when loading a glTF file.
 #[derive(Error, Debug)]
@@ -69,12 +91,654 @@ pub enum GltfError {
     MissingAnimationSampler(usize),
     #[error("failed to generate tangents: {0}")]
     GenerateTangentsError(#[from] bevy::render::mesh::GenerateTangentsError),
+    #[cfg(feature = "archive")]
+    #[error("failed to read archive: {0}")]
+    Archive(#[from] zip::result::ZipError),
+    #[cfg(feature = "archive")]
+    #[error("failed to decompress file: {0}")]
+    Decompress(#[from] std::io::Error),
+    #[cfg(feature = "archive")]
+    #[error("archive doesn't contain a glTF file")]
+    MissingArchiveGltf,
+    #[error("URI of buffer {buffer} isn't valid UTF-8")]
+    InvalidBufferUri { buffer: usize },
+    #[error("URI of image {image} isn't valid UTF-8")]
+    InvalidImageUri { image: usize },
+    #[error("image {image} has neither a MIME type nor a file extension")]
+    UnknownImageFormat { image: usize },
+    #[error("buffer {buffer} is {length} bytes long but should be at least {expected}")]
+    BufferTooShort {
+        buffer: usize,
+        length: usize,
+        expected: usize,
+    },
+    #[error("buffer view {view} is out of range of its buffer")]
+    BufferViewOutOfRange { view: usize },
+    #[error("accessor {accessor} is empty or out of range of its buffer view")]
+    AccessorOutOfRange { accessor: usize },
+    #[error("accessor {accessor} has the wrong type for its use")]
+    InvalidAccessorType { accessor: usize },
+    #[error("vertex attributes of mesh {mesh} primitive {primitive} have different lengths")]
+    VertexCountMismatch { mesh: usize, primitive: usize },
+    #[error("mesh {mesh} primitive {primitive} has a vertex index out of range")]
+    VertexIndexOutOfRange { mesh: usize, primitive: usize },
+    #[error("skin {skin} has only {matrices} inverse bind matrices for {joints} joints")]
+    InverseBindMatrixCountMismatch {
+        skin: usize,
+        matrices: usize,
+        joints: usize,
+    },
+    #[error("mesh {mesh} primitive {primitive} uses a joint out of range of skin {skin}")]
+    JointIndexOutOfRange {
+        skin: usize,
+        mesh: usize,
+        primitive: usize,
+    },
+    #[error("joint {node} of skin {skin} isn't part of the scene")]
+    MissingJointNode { skin: usize, node: usize },
+    #[error("node {node} is its own descendant")]
+    NodeCycle { node: usize },
+    #[error("node {node} can't be placed in the node hierarchy")]
+    InvalidNodeHierarchy { node: usize },
+    #[error("failed to load image {image}: {message}")]
+    ImageLoadFailed { image: usize, message: String },
+    #[error("image {image} is larger than {max_dimension} pixels but can't be downscaled")]
+    ImageNotDownscaled { image: usize, max_dimension: u32 },
+    #[error("vertex attribute {semantic:?} of mesh {mesh} primitive {primitive} has format {found:?} but {expected:?} was expected")]
+    VertexAttributeFormatMismatch {
+        mesh: usize,
+        primitive: usize,
+        semantic: gltf::Semantic,
+        found: VertexFormat,
+        expected: VertexFormat,
+    },
+    #[error("malformed vertex attribute data in accessor {accessor} of mesh {mesh} primitive {primitive}")]
+    MalformedVertexAttribute {
+        mesh: usize,
+        primitive: usize,
+        accessor: usize,
+    },
+    #[error("unsupported vertex attribute format {data_type:?},{dimensions:?},normalized={normalized} in accessor {accessor} of mesh {mesh} primitive {primitive}")]
+    UnsupportedVertexAttributeFormat {
+        mesh: usize,
+        primitive: usize,
+        accessor: usize,
+        data_type: DataType,
+        dimensions: Dimensions,
+        normalized: bool,
+    },
+    #[error("unrecognised vertex attribute {semantic:?} in mesh {mesh} primitive {primitive}")]
+    UnrecognisedVertexAttribute {
+        mesh: usize,
+        primitive: usize,
+        semantic: gltf::Semantic,
+    },
+    #[error("mesh {mesh} primitive {primitive} has joints or weights set {set} without the other")]
+    UnpairedJointSet {
+        mesh: usize,
+        primitive: usize,
+        set: u32,
+    },
+    #[error(
+        "mesh {mesh} primitive {primitive} can't be split to use fewer joints than its skin has"
+    )]
+    SkinNotPartitioned { mesh: usize, primitive: usize },
+    #[error("failed to generate tangents for mesh {mesh} primitive {primitive}: {message}")]
+    TangentGenerationFailed {
+        mesh: usize,
+        primitive: usize,
+        message: String,
+    },
+    #[error("node {node} has a child {child} which doesn't exist")]
+    MissingChildNode { node: usize, child: usize },
+    #[error("nodes {nodes:?} aren't part of a tree")]
+    NodeHierarchyNotTree { nodes: Vec<usize> },
+    #[error("interpolation {interpolation:?} of animation {animation} channel {channel} is not supported")]
+    UnsupportedInterpolation {
+        animation: usize,
+        channel: usize,
+        interpolation: gltf::animation::Interpolation,
+    },
+    #[error("sparse input of animation {animation} channel {channel} is not supported")]
+    SparseAnimationInput { animation: usize, channel: usize },
+    #[error("morph target weights in animation {animation} channel {channel} are not supported")]
+    MorphTargetAnimation { animation: usize, channel: usize },
+    #[error(
+        "animation {animation} can't target node {node}: part of its hierarchy is missing a name"
+    )]
+    UnnamedAnimationPath { animation: usize, node: usize },
+    #[error("the {key} extras of node {node} aren't a map of components")]
+    InvalidExtrasComponents { node: usize, key: String },
+    #[error("failed to insert component {component} from the extras of node {node}: {message}")]
+    ExtrasComponentFailed {
+        node: usize,
+        component: String,
+        message: String,
+    },
+    #[error("{limit} limit of {max} exceeded")]
+    LimitExceeded { limit: GltfLimit, max: usize },
+}
+
+/// A problem with a glTF file which doesn't prevent it from loading.
+#[derive(Error, Debug, Clone)]
+pub enum GltfWarning {
+    #[error("image {image} is used as both color and non-color data, loading it twice")]
+    TextureColorSpaceConflict { image: usize },
+    #[error("failed to load image {image}: {message}")]
+    ImageLoadFailed { image: usize, message: String },
+    #[error("image {image} is larger than {max_dimension} pixels but can't be downscaled")]
+    ImageNotDownscaled { image: usize, max_dimension: u32 },
+    #[error("mipmaps can't be generated for image {image} in its format")]
+    MipmapsNotGenerated { image: usize },
+    #[error("vertex attribute {semantic:?} of mesh {mesh} primitive {primitive} has format {found:?} but {expected:?} was expected")]
+    VertexAttributeFormatMismatch {
+        mesh: usize,
+        primitive: usize,
+        semantic: gltf::Semantic,
+        found: VertexFormat,
+        expected: VertexFormat,
+    },
+    #[error("malformed vertex attribute data in accessor {accessor} of mesh {mesh} primitive {primitive}")]
+    MalformedVertexAttribute {
+        mesh: usize,
+        primitive: usize,
+        accessor: usize,
+    },
+    #[error("unsupported vertex attribute format {data_type:?},{dimensions:?},normalized={normalized} in accessor {accessor} of mesh {mesh} primitive {primitive}")]
+    UnsupportedVertexAttributeFormat {
+        mesh: usize,
+        primitive: usize,
+        accessor: usize,
+        data_type: DataType,
+        dimensions: Dimensions,
+        normalized: bool,
+    },
+    #[error("unrecognised vertex attribute {semantic:?} in mesh {mesh} primitive {primitive}")]
+    UnrecognisedVertexAttribute {
+        mesh: usize,
+        primitive: usize,
+        semantic: gltf::Semantic,
+    },
+    #[error("mesh {mesh} primitive {primitive} has joints or weights set {set} without the other")]
+    UnpairedJointSet {
+        mesh: usize,
+        primitive: usize,
+        set: u32,
+    },
+    #[error(
+        "mesh {mesh} primitive {primitive} can't be split to use fewer joints than its skin has"
+    )]
+    SkinNotPartitioned { mesh: usize, primitive: usize },
+    #[error("failed to generate tangents for mesh {mesh} primitive {primitive}: {message}")]
+    TangentGenerationFailed {
+        mesh: usize,
+        primitive: usize,
+        message: String,
+    },
+    #[error("node {node} has a child {child} which doesn't exist")]
+    MissingChildNode { node: usize, child: usize },
+    #[error("nodes {nodes:?} aren't part of a tree, leaving them out")]
+    NodeHierarchyNotTree { nodes: Vec<usize> },
+    #[error("interpolation {interpolation:?} of animation {animation} channel {channel} is not supported, using linear")]
+    UnsupportedInterpolation {
+        animation: usize,
+        channel: usize,
+        interpolation: gltf::animation::Interpolation,
+    },
+    #[error("sparse input of animation {animation} channel {channel} is not supported, leaving the channel out")]
+    SparseAnimationInput { animation: usize, channel: usize },
+    #[error("morph target weights in animation {animation} channel {channel} are not supported, leaving the channel out")]
+    MorphTargetAnimation { animation: usize, channel: usize },
+    #[error(
+        "animation {animation} ignored for node {node}: part of its hierarchy is missing a name"
+    )]
+    UnnamedAnimationPath { animation: usize, node: usize },
+    #[error("the {key} extras of node {node} aren't a map of components, ignoring them")]
+    InvalidExtrasComponents { node: usize, key: String },
+    #[error("failed to insert component {component} from the extras of node {node}: {message}")]
+    ExtrasComponentFailed {
+        node: usize,
+        component: String,
+        message: String,
+    },
+}
+
+impl GltfWarning {
+    /// Returns the error which fails the load in strict mode when this warning is raised, or
+    /// `None` if the warning doesn't lose any of the file's data.
+    fn strict_error(&self) -> Option<GltfError> {
+        Some(match self.clone() {
+            GltfWarning::TextureColorSpaceConflict { .. }
+            | GltfWarning::MipmapsNotGenerated { .. } => return None,
+            GltfWarning::ImageLoadFailed { image, message } => {
+                GltfError::ImageLoadFailed { image, message }
+            }
+            GltfWarning::ImageNotDownscaled {
+                image,
+                max_dimension,
+            } => GltfError::ImageNotDownscaled {
+                image,
+                max_dimension,
+            },
+            GltfWarning::VertexAttributeFormatMismatch {
+                mesh,
+                primitive,
+                semantic,
+                found,
+                expected,
+            } => GltfError::VertexAttributeFormatMismatch {
+                mesh,
+                primitive,
+                semantic,
+                found,
+                expected,
+            },
+            GltfWarning::MalformedVertexAttribute {
+                mesh,
+                primitive,
+                accessor,
+            } => GltfError::MalformedVertexAttribute {
+                mesh,
+                primitive,
+                accessor,
+            },
+            GltfWarning::UnsupportedVertexAttributeFormat {
+                mesh,
+                primitive,
+                accessor,
+                data_type,
+                dimensions,
+                normalized,
+            } => GltfError::UnsupportedVertexAttributeFormat {
+                mesh,
+                primitive,
+                accessor,
+                data_type,
+                dimensions,
+                normalized,
+            },
+            GltfWarning::UnrecognisedVertexAttribute {
+                mesh,
+                primitive,
+                semantic,
+            } => GltfError::UnrecognisedVertexAttribute {
+                mesh,
+                primitive,
+                semantic,
+            },
+            GltfWarning::UnpairedJointSet {
+                mesh,
+                primitive,
+                set,
+            } => GltfError::UnpairedJointSet {
+                mesh,
+                primitive,
+                set,
+            },
+            GltfWarning::SkinNotPartitioned { mesh, primitive } => {
+                GltfError::SkinNotPartitioned { mesh, primitive }
+            }
+            GltfWarning::TangentGenerationFailed {
+                mesh,
+                primitive,
+                message,
+            } => GltfError::TangentGenerationFailed {
+                mesh,
+                primitive,
+                message,
+            },
+            GltfWarning::MissingChildNode { node, child } => {
+                GltfError::MissingChildNode { node, child }
+            }
+            GltfWarning::NodeHierarchyNotTree { nodes } => {
+                GltfError::NodeHierarchyNotTree { nodes }
+            }
+            GltfWarning::UnsupportedInterpolation {
+                animation,
+                channel,
+                interpolation,
+            } => GltfError::UnsupportedInterpolation {
+                animation,
+                channel,
+                interpolation,
+            },
+            GltfWarning::SparseAnimationInput { animation, channel } => {
+                GltfError::SparseAnimationInput { animation, channel }
+            }
+            GltfWarning::MorphTargetAnimation { animation, channel } => {
+                GltfError::MorphTargetAnimation { animation, channel }
+            }
+            GltfWarning::UnnamedAnimationPath { animation, node } => {
+                GltfError::UnnamedAnimationPath { animation, node }
+            }
+            GltfWarning::InvalidExtrasComponents { node, key } => {
+                GltfError::InvalidExtrasComponents { node, key }
+            }
+            GltfWarning::ExtrasComponentFailed {
+                node,
+                component,
+                message,
+            } => GltfError::ExtrasComponentFailed {
+                node,
+                component,
+                message,
+            },
+        })
+    }
+}
+
+/// Collects the [`GltfWarning`]s raised while loading a glTF file, logging each of them.
+#[derive(Default)]
+struct Warnings {
+    list: Vec<GltfWarning>,
+    strict: bool,
+}
+
+impl Warnings {
+    fn new(strict: bool) -> Self {
+        Warnings {
+            list: vec![],
+            strict,
+        }
+    }
+
+    /// Records a warning, or returns the error it corresponds to in strict mode if it loses data.
+    fn push(&mut self, warning: GltfWarning) -> Result<(), GltfError> {
+        if self.strict {
+            if let Some(error) = warning.strict_error() {
+                return Err(error);
+            }
+        }
+        warn!("{}", warning);
+        self.list.push(warning);
+        Ok(())
+    }
+
+    fn append(&mut self, other: Warnings) {
+        self.list.extend(other.list);
+    }
+}
+
+/// What to do when an image used by a glTF texture can't be loaded.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextureErrorPolicy {
+    /// Fails to load the whole glTF file.
+    Fail,
+    /// Leaves out the textures using the image, leaving their slots in materials empty.
+    #[default]
+    Skip,
+    /// Substitutes a placeholder image for the textures using the image.
+    Placeholder,
+}
+
+/// The kinds of asset loaded from glTF files. Skipped kinds aren't decoded at all, which speeds
+/// up loading files only needed for part of their contents, such as their geometry. Meshes and
+/// nodes are always loaded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GltfAssetKinds {
+    /// Loads images as textures. Materials are loaded without textures when this is false.
+    pub textures: bool,
+    /// Loads materials. Scenes use the default material when this is false.
+    pub materials: bool,
+    /// Loads scenes. The file has no scenes when this is false.
+    pub scenes: bool,
+    /// Loads animations. The file has no animations when this is false.
+    pub animations: bool,
+    /// Loads skins. Scenes contain unskinned meshes when this is false, and meshes are loaded
+    /// without their joint indices and weights.
+    pub skins: bool,
+}
+
+impl GltfAssetKinds {
+    /// Loads every kind of asset.
+    pub const ALL: Self = GltfAssetKinds {
+        textures: true,
+        materials: true,
+        scenes: true,
+        animations: true,
+        skins: true,
+    };
+
+    /// Loads only meshes and nodes.
+    pub const GEOMETRY: Self = GltfAssetKinds {
+        textures: false,
+        materials: false,
+        scenes: false,
+        animations: false,
+        skins: false,
+    };
+}
+
+impl Default for GltfAssetKinds {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+/// Limits on the resources used by glTF files, for loading untrusted files. Files exceeding any
+/// limit fail to load with a [`GltfError::LimitExceeded`], before the data concerned is read or
+/// decoded where possible. Limits which are `None` don't apply.
+#[derive(Clone, Debug, Default)]
+pub struct GltfLimits {
+    /// The total length of a file's buffers in bytes.
+    pub max_buffer_bytes: Option<usize>,
+    /// The total number of vertices in a file's mesh primitives.
+    pub max_vertices: Option<usize>,
+    /// The total number of vertex indices in a file's mesh primitives.
+    pub max_indices: Option<usize>,
+    /// The number of textures a file declares, whether or not they are used. This is checked
+    /// after parsing the file, before any image is read.
+    pub max_textures: Option<usize>,
+    /// The width or height of any texture. Unlike
+    /// [`GltfPlugin::max_texture_dimension`](crate::GltfPlugin::max_texture_dimension), this
+    /// rejects larger textures rather than downscaling them.
+    pub max_texture_dimension: Option<u32>,
+    /// The number of nodes a file declares, including nodes outside of any scene. This is checked
+    /// after parsing the file, before any node is loaded.
+    pub max_nodes: Option<usize>,
+    /// The depth of the node hierarchy, where the root nodes have a depth of 1.
+    pub max_node_depth: Option<usize>,
+    /// The total number of keyframes in a file's animation channels.
+    pub max_animation_keyframes: Option<usize>,
+    /// The total length in bytes of the files decompressed from a zip archive or
+    /// gzip-compressed file.
+    #[cfg(feature = "archive")]
+    pub max_archive_bytes: Option<usize>,
+}
+
+/// The limits in [`GltfLimits`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GltfLimit {
+    BufferBytes,
+    Vertices,
+    Indices,
+    Textures,
+    TextureDimension,
+    Nodes,
+    NodeDepth,
+    AnimationKeyframes,
+    #[cfg(feature = "archive")]
+    ArchiveBytes,
+}
+
+impl fmt::Display for GltfLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            GltfLimit::BufferBytes => "buffer bytes",
+            GltfLimit::Vertices => "vertices",
+            GltfLimit::Indices => "indices",
+            GltfLimit::Textures => "textures",
+            GltfLimit::TextureDimension => "texture dimension",
+            GltfLimit::Nodes => "nodes",
+            GltfLimit::NodeDepth => "node depth",
+            GltfLimit::AnimationKeyframes => "animation keyframes",
+            #[cfg(feature = "archive")]
+            GltfLimit::ArchiveBytes => "archive bytes",
+        })
+    }
+}
+
+/// Returns a [`GltfError::LimitExceeded`] if `value` exceeds `max`.
+pub(crate) fn check_limit(
+    limit: GltfLimit,
+    max: Option<usize>,
+    value: usize,
+) -> Result<(), GltfError> {
+    match max {
+        Some(max) if value > max => Err(GltfError::LimitExceeded { limit, max }),
+        _ => Ok(()),
+    }
+}
+
+/// How many joints may influence each vertex of a skinned mesh. Vertices influenced by more
+/// joints keep the ones with the greatest weights, and the weights of every vertex are normalized
+/// to sum to one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum JointInfluences {
+    /// Keeps four joints in Bevy's standard joint attributes.
+    #[default]
+    Four,
+    /// Keeps eight joints, the last four of which are stored in [`ATTRIBUTE_JOINT_INDEX_1`] and
+    /// [`ATTRIBUTE_JOINT_WEIGHT_1`] for use by custom shaders.
+    Eight,
+}
+
+impl JointInfluences {
+    fn count(self) -> usize {
+        match self {
+            JointInfluences::Four => 4,
+            JointInfluences::Eight => 8,
+        }
+    }
+}
+
+/// The indices of the fifth to eighth joints influencing each vertex with
+/// [`JointInfluences::Eight`].
+pub const ATTRIBUTE_JOINT_INDEX_1: MeshVertexAttribute =
+    MeshVertexAttribute::new("Vertex_JointIndex_1", 1_408_312_457, VertexFormat::Uint16x4);
+/// The weights of the fifth to eighth joints influencing each vertex with
+/// [`JointInfluences::Eight`].
+pub const ATTRIBUTE_JOINT_WEIGHT_1: MeshVertexAttribute = MeshVertexAttribute::new(
+    "Vertex_JointWeight_1",
+    1_408_312_458,
+    VertexFormat::Float32x4,
+);
+
+/// The ways in which a glTF material can use a texture.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TextureRole {
+    BaseColor,
+    Normal,
+    MetallicRoughness,
+    Occlusion,
+    Emissive,
+}
+
+impl TextureRole {
+    /// Returns true if textures in this role contain sRGB color data.
+    pub fn is_srgb(self) -> bool {
+        matches!(self, TextureRole::BaseColor | TextureRole::Emissive)
+    }
+}
+
+/// Limits on the dimensions of loaded textures, overall and by [`TextureRole`].
+#[derive(Clone, Debug, Default)]
+pub(crate) struct TextureSizeLimits {
+    pub(crate) max_dimension: Option<u32>,
+    pub(crate) max_dimension_by_role: HashMap<TextureRole, u32>,
+}
+
+impl TextureSizeLimits {
+    /// Returns the most restrictive limit applying to an image used in all of `roles`.
+    fn max_dimension(&self, roles: &[TextureRole]) -> Option<u32> {
+        if roles.is_empty() {
+            return self.max_dimension;
+        }
+        roles
+            .iter()
+            .filter_map(|role| {
+                self.max_dimension_by_role
+                    .get(role)
+                    .copied()
+                    .or(self.max_dimension)
+            })
+            .min()
+    }
+}
+
+/// Adjustments applied to the samplers of all textures loaded from glTF files.
+#[derive(Clone, Debug, Default)]
+pub struct GltfSamplerPolicy {
+    /// Enables anisotropic filtering with up to this many samples for textures which are
+    /// filtered linearly in all respects. Valid values are 1, 2, 4, 8 and 16.
+    pub anisotropy_clamp: Option<NonZeroU8>,
+    /// Replaces the magnification, minification and mipmap filters of every texture, for example
+    /// with [`FilterMode::Nearest`] for pixel art.
+    pub filter: Option<FilterMode>,
+    /// Replaces the lowest mip level which may be sampled. wgpu has no LOD bias, but raising
+    /// this has a similar effect on distant textures.
+    pub lod_min_clamp: Option<f32>,
+    /// Replaces the highest mip level which may be sampled.
+    pub lod_max_clamp: Option<f32>,
+}
+
+impl GltfSamplerPolicy {
+    fn apply(&self, sampler: &mut SamplerDescriptor) {
+        if let Some(filter) = self.filter {
+            sampler.mag_filter = filter;
+            sampler.min_filter = filter;
+            sampler.mipmap_filter = filter;
+        }
+        if let Some(lod_min_clamp) = self.lod_min_clamp {
+            sampler.lod_min_clamp = lod_min_clamp;
+        }
+        if let Some(lod_max_clamp) = self.lod_max_clamp {
+            sampler.lod_max_clamp = lod_max_clamp;
+        }
+        if [
+            sampler.mag_filter,
+            sampler.min_filter,
+            sampler.mipmap_filter,
+        ]
+        .iter()
+        .all(|filter| *filter == FilterMode::Linear)
+        {
+            sampler.anisotropy_clamp = self.anisotropy_clamp;
+        }
+    }
 }
 
+/// A function which adjusts the sampler of a loaded texture given its glTF sampler and the roles
+/// the texture is used in.
+pub type SamplerOverride =
+    dyn Fn(&gltf::texture::Sampler, &[TextureRole], &mut SamplerDescriptor<'static>) + Send + Sync;
+
+/// A function which inserts components into an entity spawned from a glTF node, primitive or
+/// light, given the value of a property of its extras.
+#[cfg(feature = "serde")]
+pub type ExtrasHandler = dyn Fn(&serde_json::Value, &mut EntityMut) + Send + Sync;
+
 /// Loads glTF files with all of their data as their corresponding bevy representations.
 pub struct GltfLoader {
     pub(crate) supported_compressed_formats: CompressedImageFormats,
     pub(crate) custom_vertex_attributes: HashMap<String, MeshVertexAttribute>,
+    pub(crate) generate_mipmaps: bool,
+    pub(crate) texture_size_limits: TextureSizeLimits,
+    pub(crate) sampler_policy: GltfSamplerPolicy,
+    pub(crate) sampler_override: Option<Arc<SamplerOverride>>,
+    pub(crate) texture_error_policy: TextureErrorPolicy,
+    pub(crate) uri_resolver: Option<Arc<dyn UriResolver>>,
+    pub(crate) asset_kinds: GltfAssetKinds,
+    pub(crate) strict: bool,
+    pub(crate) limits: GltfLimits,
+    pub(crate) joint_influences: JointInfluences,
+    #[cfg(feature = "serde")]
+    pub(crate) extras_components_key: Option<String>,
+    #[cfg(feature = "serde")]
+    pub(crate) type_registry: AppTypeRegistry,
+    #[cfg(feature = "serde")]
+    pub(crate) extras_handlers: HashMap<String, Arc<ExtrasHandler>>,
+    pub(crate) scene_post_processors: Vec<Arc<dyn ScenePostProcessor>>,
 }
 
 impl AssetLoader for GltfLoader {
@@ -87,10 +751,17 @@ impl AssetLoader for GltfLoader {
     }
 
     fn extensions(&self) -> &[&str] {
-        &["gltf", "glb"]
+        EXTENSIONS
     }
 }
 
+/// The extensions of the files loaded by [`GltfLoader`].
+#[cfg(not(feature = "archive"))]
+const EXTENSIONS: &[&str] = &["gltf", "glb"];
+/// The extensions of the files loaded by [`GltfLoader`], including compressed files.
+#[cfg(feature = "archive")]
+const EXTENSIONS: &[&str] = &["gltf", "glb", "gltf.zip", "glb.zip", "gltf.gz", "glb.gz"];
+
 /// Represents whether integer data requires normalization
 #[derive(Copy, Clone)]
 struct Normalization(bool);
@@ -119,7 +790,7 @@ enum AccessFailed {
 /// Helper for reading buffer data
 struct BufferAccessor<'a> {
     accessor: gltf::Accessor<'a>,
-    buffer_data: &'a Vec<Vec<u8>>,
+    buffer_data: &'a [Cow<'a, [u8]>],
     normalization: Normalization,
 }
 
@@ -127,7 +798,7 @@ impl<'a> BufferAccessor<'a> {
     /// Creates an iterator over the elements in this accessor
     fn iter<T: gltf::accessor::Item>(self) -> Result<gltf::accessor::Iter<'a, T>, AccessFailed> {
         gltf::accessor::Iter::new(self.accessor, |buffer: gltf::Buffer| {
-            self.buffer_data.get(buffer.index()).map(|v| v.as_slice())
+            self.buffer_data.get(buffer.index()).map(|v| v.as_ref())
         })
         .ok_or(AccessFailed::MalformedData)
     }
@@ -181,7 +852,7 @@ impl<'a> VertexAttributeIter<'a> {
     /// Creates an iterator over the elements in a vertex attribute accessor
     fn from_accessor(
         accessor: gltf::Accessor<'a>,
-        buffer_data: &'a Vec<Vec<u8>>,
+        buffer_data: &'a [Cow<'a, [u8]>],
     ) -> Result<VertexAttributeIter<'a>, AccessFailed> {
         let normalization = Normalization(accessor.normalized());
         let format = (accessor.data_type(), accessor.dimensions());
@@ -300,6 +971,19 @@ impl<'a> VertexAttributeIter<'a> {
         }
     }
 
+    /// Materializes joint weight values, converting compatible formats to Float32x4
+    fn into_joint_weight_values(self) -> Result<VertexAttributeValues, AccessFailed> {
+        match self {
+            VertexAttributeIter::U8x4(it, Normalization(true)) => Ok(
+                VertexAttributeValues::Float32x4(ReadWeights::U8(it).into_f32().collect()),
+            ),
+            VertexAttributeIter::U16x4(it, Normalization(true)) => Ok(
+                VertexAttributeValues::Float32x4(ReadWeights::U16(it).into_f32().collect()),
+            ),
+            s => s.into_any_values(),
+        }
+    }
+
     /// Materializes texture coordinate values, converting compatible formats to Float32x2
     fn into_tex_coord_values(self) -> Result<VertexAttributeValues, AccessFailed> {
         match self {
@@ -318,6 +1002,7 @@ enum VertexAttributeConversion {
     Any,
     Rgba,
     JointIndex,
+    JointWeight,
     TexCoord,
 }
 
@@ -327,29 +1012,262 @@ async fn load_gltf<'a, 'b>(
     load_context: &'a mut LoadContext<'b>,
     loader: &GltfLoader,
 ) -> Result<(), GltfError> {
-    let gltf = gltf::Gltf::from_slice(bytes)?;
-    let buffer_data = load_buffers(&gltf, load_context, load_context.path()).await?;
+    let external_files = ExternalFiles {
+        #[cfg(feature = "archive")]
+        archive: Archive::unpack(bytes, load_context.path(), &loader.limits)?,
+        ..Default::default()
+    };
+    #[cfg(feature = "archive")]
+    let bytes = external_files
+        .archive
+        .as_ref()
+        .map_or(bytes, |archive| &archive.gltf);
+
+    let (gltf, blob) = parse_gltf(bytes)?;
+    validate_node_graph(&gltf)?;
+    check_limits(&gltf, &loader.limits)?;
+    let buffer_data = load_buffers(&gltf, blob, load_context, loader, &external_files).await?;
+    validate_accessors(&gltf)?;
+    validate_accessor_types(&gltf)?;
+
+    let asset_kinds = loader.asset_kinds;
+    let mut texture_roles = HashMap::<usize, Vec<TextureRole>>::new();
+    if asset_kinds.textures {
+        for material in gltf.materials() {
+            for (texture, role) in material_textures(&material) {
+                texture_roles.entry(texture.index()).or_default().push(role);
+            }
+        }
+    }
+
+    let mut warnings = Warnings::new(loader.strict);
+
+    // Each source image is decoded once, and made into an image for each way it is used: as
+    // color data, as other non-color data and as a normal map. An image used as both color and
+    // non-color data is loaded once in each color space.
+    let mut texture_usages = vec![];
+    let mut image_roles = HashMap::<ImageKey, Vec<TextureRole>>::new();
+    let mut image_keys = BTreeMap::<usize, Vec<ImageKey>>::new();
+    if asset_kinds.textures {
+        for texture in gltf.textures() {
+            let roles = texture_roles
+                .get(&texture.index())
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            // Textures which aren't used by any material are loaded as color data
+            let mut usages = roles
+                .iter()
+                .map(|&role| ImageUsage::for_role(role))
+                .collect::<Vec<_>>();
+            if usages.is_empty() {
+                usages.push(ImageUsage::Color);
+            }
+            usages.sort_unstable();
+            usages.dedup();
+            for &usage in &usages {
+                let image_key = ImageKey {
+                    image: texture.source().index(),
+                    usage,
+                };
+                let image_roles = image_roles.entry(image_key).or_insert_with(|| {
+                    image_keys
+                        .entry(image_key.image)
+                        .or_default()
+                        .push(image_key);
+                    vec![]
+                });
+                for &role in roles
+                    .iter()
+                    .filter(|&&role| ImageUsage::for_role(role) == usage)
+                {
+                    if !image_roles.contains(&role) {
+                        image_roles.push(role);
+                    }
+                }
+            }
+            texture_usages.push((texture, usages));
+        }
+    }
+    for (&image, image_keys) in &mut image_keys {
+        image_keys.sort_unstable_by_key(|image_key| image_key.usage);
+        if image_keys.len() > 1 && image_keys[0].usage == ImageUsage::Color {
+            warnings.push(GltfWarning::TextureColorSpaceConflict { image })?;
+        }
+    }
+
+    // TODO: use the threaded impl on wasm once wasm thread pool doesn't deadlock on it
+    // See https://github.com/bevyengine/bevy/issues/1924 for more details
+    // The taskpool use is also avoided when there is only one image for performance reasons and
+    // to avoid https://github.com/bevyengine/bevy/pull/2725
+    let image_uses = image_keys
+        .iter()
+        .map(|(&image, image_keys)| {
+            let uses = image_keys
+                .iter()
+                .map(|image_key| (image_key.usage, image_roles[image_key].as_slice()))
+                .collect::<Vec<_>>();
+            (image, uses)
+        })
+        .collect::<Vec<_>>();
+    let mut image_results = vec![];
+    if image_uses.len() == 1 || cfg!(target_arch = "wasm32") {
+        for (image, uses) in &image_uses {
+            let result = load_image(
+                gltf.images().nth(*image).unwrap(),
+                uses,
+                &buffer_data,
+                load_context,
+                loader,
+                &external_files,
+            )
+            .await;
+            image_results.push((*image, result));
+        }
+    } else {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            image_results = IoTaskPool::get().scope(|scope| {
+                image_uses.iter().for_each(|(image, uses)| {
+                    let gltf_image = gltf.images().nth(*image).unwrap();
+                    let load_context: &LoadContext = load_context;
+                    let buffer_data = &buffer_data;
+                    let external_files = &external_files;
+                    scope.spawn(async move {
+                        let result = load_image(
+                            gltf_image,
+                            uses,
+                            buffer_data,
+                            load_context,
+                            loader,
+                            external_files,
+                        )
+                        .await;
+                        (*image, result)
+                    });
+                });
+            });
+        }
+    }
+
+    let mut images = HashMap::new();
+    for (image, result) in image_results {
+        let image_keys = &image_keys[&image];
+        let decoded = match result {
+            Ok((decoded, image_warnings)) => {
+                warnings.append(image_warnings);
+                decoded
+            }
+            Err(err) => {
+                let (warning, placeholders) =
+                    failed_image_placeholders(image, image_keys, err, loader)?;
+                warnings.push(warning)?;
+                match placeholders {
+                    Some(placeholders) => placeholders,
+                    None => continue,
+                }
+            }
+        };
+        images.extend(image_keys.iter().copied().zip(decoded));
+    }
+
+    // Bevy keeps the sampler in the image, so each decoded image is copied for every distinct
+    // sampler of the textures using it, and textures with the same sampler share a copy
+    let image_key_label = |image_key: ImageKey, sampler_index: usize| {
+        let is_primary = image_keys[&image_key.image][0] == image_key;
+        image_label(image_key, is_primary, sampler_index)
+    };
+    let mut texture_labels = TextureLabels::default();
+    let mut image_samplers = HashMap::<ImageKey, Vec<SamplerDescriptor<'static>>>::new();
+    for (texture, usages) in texture_usages {
+        // Textures whose image failed to load are left out, along with their slots in materials
+        let texture_image_keys = usages
+            .iter()
+            .map(|&usage| ImageKey {
+                image: texture.source().index(),
+                usage,
+            })
+            .collect::<Vec<_>>();
+        if !texture_image_keys
+            .iter()
+            .all(|image_key| images.contains_key(image_key))
+        {
+            continue;
+        }
+
+        let mut sampler = texture_sampler(&texture);
+        loader.sampler_policy.apply(&mut sampler);
+        if let Some(sampler_override) = &loader.sampler_override {
+            let roles = texture_roles
+                .get(&texture.index())
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            sampler_override(&texture.sampler(), roles, &mut sampler);
+        }
+
+        let mut texture_images = vec![];
+        for image_key in texture_image_keys {
+            let samplers = image_samplers.entry(image_key).or_default();
+            let sampler_index = match samplers.iter().position(|other| *other == sampler) {
+                Some(index) => index,
+                None => {
+                    samplers.push(sampler.clone());
+                    samplers.len() - 1
+                }
+            };
+            let label = image_key_label(image_key, sampler_index);
+            texture_images.push((
+                image_key.usage,
+                load_context.get_handle(AssetPath::new_ref(load_context.path(), Some(&label))),
+            ));
+            texture_labels
+                .0
+                .insert((texture.index(), image_key.usage), label);
+        }
+
+        let copy = |usage: ImageUsage| {
+            texture_images[1..]
+                .iter()
+                .find(|(image_usage, _)| *image_usage == usage)
+                .map(|(_, image)| image.clone())
+        };
+        load_context.set_labeled_asset(
+            &texture_label(&texture),
+            LoadedAsset::new(GltfTexture {
+                image: texture_images[0].1.clone(),
+                linear_image: copy(ImageUsage::Data),
+                normal_map_image: copy(ImageUsage::NormalMap),
+                sampler,
+            }),
+        );
+    }
+    for (image_key, samplers) in image_samplers {
+        let Some(mut image) = images.remove(&image_key) else {
+            continue;
+        };
+        let copies = samplers.len();
+        for (sampler_index, sampler) in samplers.into_iter().enumerate() {
+            let mut image = if sampler_index + 1 < copies {
+                image.clone()
+            } else {
+                std::mem::take(&mut image)
+            };
+            image.sampler_descriptor = ImageSampler::Descriptor(sampler);
+            load_context.set_labeled_asset(
+                &image_key_label(image_key, sampler_index),
+                LoadedAsset::new(image),
+            );
+        }
+    }
 
     let mut materials = vec![];
     let mut named_materials = HashMap::default();
-    let mut linear_textures = HashSet::default();
-    for material in gltf.materials() {
-        let handle = load_material(&material, load_context);
-        if let Some(name) = material.name() {
-            named_materials.insert(name.to_string(), handle.clone());
-        }
-        materials.push(handle);
-        if let Some(texture) = material.normal_texture() {
-            linear_textures.insert(texture.texture().index());
-        }
-        if let Some(texture) = material.occlusion_texture() {
-            linear_textures.insert(texture.texture().index());
-        }
-        if let Some(texture) = material
-            .pbr_metallic_roughness()
-            .metallic_roughness_texture()
-        {
-            linear_textures.insert(texture.texture().index());
+    if asset_kinds.materials {
+        for material in gltf.materials() {
+            let handle = load_material(&material, &texture_labels, load_context);
+            if let Some(name) = material.name() {
+                named_materials.insert(name.to_string(), handle.clone());
+            }
+            materials.push(handle);
         }
     }
 
@@ -370,207 +1288,186 @@ async fn load_gltf<'a, 'b>(
         let mut animations = vec![];
         let mut named_animations = HashMap::default();
         let mut animation_roots = HashSet::default();
-        for animation in gltf.animations() {
-            let mut animation_clip = bevy_animation::AnimationClip::default();
-            for channel in animation.channels() {
-                match channel.sampler().interpolation() {
-                    gltf::animation::Interpolation::Linear => (),
-                    other => warn!(
-                        "Animation interpolation {:?} is not supported, will use linear",
-                        other
-                    ),
-                };
-                let node = channel.target().node();
-                let reader = channel.reader(|buffer| Some(&buffer_data[buffer.index()]));
-                let keyframe_timestamps: Vec<f32> = if let Some(inputs) = reader.read_inputs() {
-                    match inputs {
-                        gltf::accessor::Iter::Standard(times) => times.collect(),
-                        gltf::accessor::Iter::Sparse(_) => {
-                            warn!("Sparse accessor not supported for animation sampler input");
-                            continue;
+        if asset_kinds.animations {
+            for animation in gltf.animations() {
+                let mut animation_clip = bevy_animation::AnimationClip::default();
+                for channel in animation.channels() {
+                    match channel.sampler().interpolation() {
+                        gltf::animation::Interpolation::Linear => (),
+                        interpolation => warnings.push(GltfWarning::UnsupportedInterpolation {
+                            animation: animation.index(),
+                            channel: channel.index(),
+                            interpolation,
+                        })?,
+                    };
+                    let node = channel.target().node();
+                    let reader = channel
+                        .reader(|buffer| buffer_data.get(buffer.index()).map(|data| data.as_ref()));
+                    let keyframe_timestamps: Vec<f32> = if let Some(inputs) = reader.read_inputs() {
+                        match inputs {
+                            gltf::accessor::Iter::Standard(times) => times.collect(),
+                            gltf::accessor::Iter::Sparse(_) => {
+                                warnings.push(GltfWarning::SparseAnimationInput {
+                                    animation: animation.index(),
+                                    channel: channel.index(),
+                                })?;
+                                continue;
+                            }
                         }
-                    }
-                } else {
-                    warn!("Animations without a sampler input are not supported");
-                    return Err(GltfError::MissingAnimationSampler(animation.index()));
-                };
+                    } else {
+                        warn!("Animations without a sampler input are not supported");
+                        return Err(GltfError::MissingAnimationSampler(animation.index()));
+                    };
 
-                let keyframes = if let Some(outputs) = reader.read_outputs() {
-                    match outputs {
-                        gltf::animation::util::ReadOutputs::Translations(tr) => {
-                            bevy_animation::Keyframes::Translation(tr.map(Vec3::from).collect())
-                        }
-                        gltf::animation::util::ReadOutputs::Rotations(rots) => {
-                            bevy_animation::Keyframes::Rotation(
-                                rots.into_f32().map(bevy::math::Quat::from_array).collect(),
-                            )
-                        }
-                        gltf::animation::util::ReadOutputs::Scales(scale) => {
-                            bevy_animation::Keyframes::Scale(scale.map(Vec3::from).collect())
-                        }
-                        gltf::animation::util::ReadOutputs::MorphTargetWeights(_) => {
-                            warn!("Morph animation property not yet supported");
-                            continue;
+                    let keyframes = if let Some(outputs) = reader.read_outputs() {
+                        match outputs {
+                            gltf::animation::util::ReadOutputs::Translations(tr) => {
+                                bevy_animation::Keyframes::Translation(tr.map(Vec3::from).collect())
+                            }
+                            gltf::animation::util::ReadOutputs::Rotations(rots) => {
+                                bevy_animation::Keyframes::Rotation(
+                                    rots.into_f32().map(bevy::math::Quat::from_array).collect(),
+                                )
+                            }
+                            gltf::animation::util::ReadOutputs::Scales(scale) => {
+                                bevy_animation::Keyframes::Scale(scale.map(Vec3::from).collect())
+                            }
+                            gltf::animation::util::ReadOutputs::MorphTargetWeights(_) => {
+                                warnings.push(GltfWarning::MorphTargetAnimation {
+                                    animation: animation.index(),
+                                    channel: channel.index(),
+                                })?;
+                                continue;
+                            }
                         }
-                    }
-                } else {
-                    warn!("Animations without a sampler output are not supported");
-                    return Err(GltfError::MissingAnimationSampler(animation.index()));
-                };
+                    } else {
+                        warn!("Animations without a sampler output are not supported");
+                        return Err(GltfError::MissingAnimationSampler(animation.index()));
+                    };
 
-                if let Some((root_index, path)) = paths.get(&node.index()) {
-                    animation_roots.insert(root_index);
-                    animation_clip.add_curve_to_path(
-                        bevy_animation::EntityPath {
-                            parts: path.clone(),
-                        },
-                        bevy_animation::VariableCurve {
-                            keyframe_timestamps,
-                            keyframes,
-                        },
-                    );
-                } else {
-                    warn!(
-                        "Animation ignored for node {}: part of its hierarchy is missing a name",
-                        node.index()
-                    );
+                    if let Some((root_index, path)) = paths.get(&node.index()) {
+                        animation_roots.insert(root_index);
+                        animation_clip.add_curve_to_path(
+                            bevy_animation::EntityPath {
+                                parts: path.clone(),
+                            },
+                            bevy_animation::VariableCurve {
+                                keyframe_timestamps,
+                                keyframes,
+                            },
+                        );
+                    } else {
+                        warnings.push(GltfWarning::UnnamedAnimationPath {
+                            animation: animation.index(),
+                            node: node.index(),
+                        })?;
+                    }
                 }
+                let handle = load_context.set_labeled_asset(
+                    &format!("Animation{}", animation.index()),
+                    LoadedAsset::new(animation_clip),
+                );
+                if let Some(name) = animation.name() {
+                    named_animations.insert(name.to_string(), handle.clone());
+                }
+                animations.push(handle);
             }
-            let handle = load_context.set_labeled_asset(
-                &format!("Animation{}", animation.index()),
-                LoadedAsset::new(animation_clip),
-            );
-            if let Some(name) = animation.name() {
-                named_animations.insert(name.to_string(), handle.clone());
-            }
-            animations.push(handle);
         }
         (animations, named_animations, animation_roots)
     };
 
+    // Primitives are decoded in parallel and labelled in order once they have all been decoded.
+    // The taskpool use is avoided for a single primitive and on wasm, as for images.
+    let gltf_primitives = gltf
+        .meshes()
+        .flat_map(|mesh| {
+            let mesh_index = mesh.index();
+            mesh.primitives()
+                .map(move |primitive| (mesh_index, primitive))
+        })
+        .collect::<Vec<_>>();
+    let mut primitive_results = vec![];
+    if gltf_primitives.len() == 1 || cfg!(target_arch = "wasm32") {
+        for (mesh_index, primitive) in &gltf_primitives {
+            let mut primitive_warnings = Warnings::new(loader.strict);
+            let result = load_primitive_mesh(
+                *mesh_index,
+                primitive,
+                &buffer_data,
+                loader,
+                &mut primitive_warnings,
+            );
+            primitive_results.push((result, primitive_warnings));
+        }
+    } else {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            primitive_results = IoTaskPool::get().scope(|scope| {
+                gltf_primitives.iter().for_each(|(mesh_index, primitive)| {
+                    let buffer_data = &buffer_data;
+                    scope.spawn(async move {
+                        let mut primitive_warnings = Warnings::new(loader.strict);
+                        let result = load_primitive_mesh(
+                            *mesh_index,
+                            primitive,
+                            buffer_data,
+                            loader,
+                            &mut primitive_warnings,
+                        );
+                        (result, primitive_warnings)
+                    });
+                });
+            });
+        }
+    }
+    let mut primitive_meshes = primitive_results.into_iter();
+
+    // Primitives used with skins which have more joints than Bevy supports are also split into
+    // parts using fewer joints, which are spawned in their place
+    let partitioned_meshes = gltf
+        .nodes()
+        .filter(|node| {
+            asset_kinds.skins
+                && node
+                    .skin()
+                    .is_some_and(|skin| skin.joints().len() > MAX_JOINTS)
+        })
+        .filter_map(|node| node.mesh().map(|mesh| mesh.index()))
+        .collect::<HashSet<_>>();
+    let mut mesh_partitions = HashMap::new();
+
     let mut meshes = vec![];
     let mut named_meshes = HashMap::default();
     for mesh in gltf.meshes() {
         let mut primitives = vec![];
         for primitive in mesh.primitives() {
             let primitive_label = primitive_label(&mesh, &primitive);
-            let primitive_topology = get_primitive_topology(primitive.mode())?;
-
-            let mut mesh = Mesh::new(primitive_topology);
-
-            // Read vertex attributes
-            for (semantic, accessor) in primitive.attributes() {
-                if let Some((attribute, conversion)) = match &semantic {
-                    gltf::Semantic::Positions => {
-                        Some((Mesh::ATTRIBUTE_POSITION, VertexAttributeConversion::Any))
-                    }
-                    gltf::Semantic::Normals => {
-                        Some((Mesh::ATTRIBUTE_NORMAL, VertexAttributeConversion::Any))
-                    }
-                    gltf::Semantic::Tangents => {
-                        Some((Mesh::ATTRIBUTE_TANGENT, VertexAttributeConversion::Any))
-                    }
-                    gltf::Semantic::Colors(0) => {
-                        Some((Mesh::ATTRIBUTE_COLOR, VertexAttributeConversion::Rgba))
-                    }
-                    gltf::Semantic::TexCoords(0) => {
-                        Some((Mesh::ATTRIBUTE_UV_0, VertexAttributeConversion::TexCoord))
-                    }
-                    gltf::Semantic::Joints(0) => Some((
-                        Mesh::ATTRIBUTE_JOINT_INDEX,
-                        VertexAttributeConversion::JointIndex,
-                    )),
-                    gltf::Semantic::Weights(0) => {
-                        Some((Mesh::ATTRIBUTE_JOINT_WEIGHT, VertexAttributeConversion::Any))
-                    }
-                    gltf::Semantic::Extras(name) => loader
-                        .custom_vertex_attributes
-                        .get(name)
-                        .map(|attr| (attr.clone(), VertexAttributeConversion::Any)),
-                    _ => None,
-                } {
-                    let raw_iter =
-                        VertexAttributeIter::from_accessor(accessor.clone(), &buffer_data);
-                    let converted_values = raw_iter.and_then(|iter| match conversion {
-                        VertexAttributeConversion::Any => iter.into_any_values(),
-                        VertexAttributeConversion::Rgba => iter.into_rgba_values(),
-                        VertexAttributeConversion::TexCoord => iter.into_tex_coord_values(),
-                        VertexAttributeConversion::JointIndex => iter.into_joint_index_values(),
-                    });
-                    match converted_values {
-                        Ok(values) => {
-                            let loaded_format = VertexFormat::from(&values);
-                            if attribute.format == loaded_format {
-                                mesh.insert_attribute(attribute, values);
-                            } else {
-                                warn!("Vertex attribute {:?} has format {:?} but expected {:?} for target attribute {}",
-                                    semantic, loaded_format, attribute.format, attribute.name
+            let (result, primitive_warnings) = primitive_meshes.next().unwrap();
+            warnings.append(primitive_warnings);
+            let partition_key = (mesh.index(), primitive.index());
+            let mesh = result?;
+            if partitioned_meshes.contains(&partition_key.0) {
+                match partition_skinned_mesh(&mesh, MAX_JOINTS) {
+                    Some(partitions) => {
+                        let partitions = partitions
+                            .into_iter()
+                            .enumerate()
+                            .map(|(index, partition)| {
+                                let mesh = load_context.set_labeled_asset(
+                                    &partition_label(&primitive_label, index),
+                                    LoadedAsset::new(partition.mesh),
                                 );
-                            }
-                        }
-                        Err(AccessFailed::MalformedData) => {
-                            warn!(
-                                "Malformed vertex attribute data in accessor {}",
-                                accessor.index()
-                            );
-                        }
-                        Err(AccessFailed::UnsupportedFormat) => {
-                            warn!("Unsupported vertex attribute format {:?},{:?},normalized={} in accessor {}",
-                                accessor.data_type(), accessor.dimensions(), accessor.normalized(), accessor.index()
-                            );
-                        }
+                                (mesh, partition.joints)
+                            })
+                            .collect::<Vec<_>>();
+                        mesh_partitions.insert(partition_key, partitions);
                     }
-                } else {
-                    warn!("Unrecognised vertex attribute {:?}", semantic);
-                }
-            }
-
-            // Read vertex indices
-            let reader = primitive.reader(|buffer| Some(buffer_data[buffer.index()].as_slice()));
-            if let Some(indices) = reader.read_indices() {
-                mesh.set_indices(Some(match indices {
-                    ReadIndices::U8(is) => Indices::U16(is.map(|x| x as u16).collect()),
-                    ReadIndices::U16(is) => Indices::U16(is.collect()),
-                    ReadIndices::U32(is) => Indices::U32(is.collect()),
-                }));
-            };
-
-            if mesh.attribute(Mesh::ATTRIBUTE_NORMAL).is_none()
-                && matches!(mesh.primitive_topology(), PrimitiveTopology::TriangleList)
-            {
-                let vertex_count_before = mesh.count_vertices();
-                mesh.duplicate_vertices();
-                mesh.compute_flat_normals();
-                let vertex_count_after = mesh.count_vertices();
-
-                if vertex_count_before != vertex_count_after {
-                    bevy::log::debug!("Missing vertex normals in indexed geometry, computing them as flat. Vertex count increased from {} to {}", vertex_count_before, vertex_count_after);
-                } else {
-                    bevy::log::debug!(
-                        "Missing vertex normals in indexed geometry, computing them as flat."
-                    );
-                }
-            }
-
-            if let Some(vertex_attribute) = reader
-                .read_tangents()
-                .map(|v| VertexAttributeValues::Float32x4(v.collect()))
-            {
-                mesh.insert_attribute(Mesh::ATTRIBUTE_TANGENT, vertex_attribute);
-            } else if mesh.attribute(Mesh::ATTRIBUTE_NORMAL).is_some()
-                && primitive.material().normal_texture().is_some()
-            {
-                bevy::log::debug!(
-                    "Missing vertex tangents, computing them using the mikktspace algorithm"
-                );
-                if let Err(err) = mesh.generate_tangents() {
-                    bevy::log::warn!(
-                        "Failed to generate vertex tangents using the mikktspace algorithm: {:?}",
-                        err
-                    );
+                    None => warnings.push(GltfWarning::SkinNotPartitioned {
+                        mesh: partition_key.0,
+                        primitive: partition_key.1,
+                    })?,
                 }
             }
-
             let mesh = load_context.set_labeled_asset(&primitive_label, LoadedAsset::new(mesh));
             primitives.push(super::GltfPrimitive {
                 mesh,
@@ -632,7 +1529,7 @@ async fn load_gltf<'a, 'b>(
             named_nodes_intermediate.insert(name, node.index());
         }
     }
-    let nodes = resolve_node_hierarchy(nodes_intermediate, load_context.path())
+    let nodes = resolve_node_hierarchy(nodes_intermediate, &mut warnings)?
         .into_iter()
         .map(|(label, node)| load_context.set_labeled_asset(&label, LoadedAsset::new(node)))
         .collect::<Vec<bevy::asset::Handle<GltfNode>>>();
@@ -645,90 +1542,61 @@ async fn load_gltf<'a, 'b>(
         })
         .collect();
 
-    // TODO: use the threaded impl on wasm once wasm thread pool doesn't deadlock on it
-    // See https://github.com/bevyengine/bevy/issues/1924 for more details
-    // The taskpool use is also avoided when there is only one texture for performance reasons and
-    // to avoid https://github.com/bevyengine/bevy/pull/2725
-    if gltf.textures().len() == 1 || cfg!(target_arch = "wasm32") {
-        for gltf_texture in gltf.textures() {
-            let (texture, label) = load_texture(
-                gltf_texture,
-                &buffer_data,
-                &linear_textures,
-                load_context,
-                loader.supported_compressed_formats,
-            )
-            .await?;
-            load_context.set_labeled_asset(&label, LoadedAsset::new(texture));
-        }
-    } else {
-        #[cfg(not(target_arch = "wasm32"))]
-        IoTaskPool::get()
-            .scope(|scope| {
-                gltf.textures().for_each(|gltf_texture| {
-                    let linear_textures = &linear_textures;
-                    let load_context: &LoadContext = load_context;
-                    let buffer_data = &buffer_data;
-                    scope.spawn(async move {
-                        load_texture(
-                            gltf_texture,
-                            buffer_data,
-                            linear_textures,
-                            load_context,
-                            loader.supported_compressed_formats,
-                        )
-                        .await
-                    });
-                });
-            })
-            .into_iter()
-            .filter_map(|res| {
-                if let Err(err) = res.as_ref() {
-                    warn!("Error loading glTF texture: {}", err);
-                }
-                res.ok()
-            })
-            .for_each(|(texture, label)| {
-                load_context.set_labeled_asset(&label, LoadedAsset::new(texture));
-            });
-    }
-
     let skinned_mesh_inverse_bindposes: Vec<_> = gltf
         .skins()
+        .filter(|_| asset_kinds.skins)
         .map(|gltf_skin| {
-            let reader = gltf_skin.reader(|buffer| Some(&buffer_data[buffer.index()]));
-            let inverse_bindposes: Vec<Mat4> = reader
-                .read_inverse_bind_matrices()
-                .unwrap()
-                .map(|mat| Mat4::from_cols_array_2d(&mat))
-                .collect();
+            let reader = gltf_skin
+                .reader(|buffer| buffer_data.get(buffer.index()).map(|data| data.as_ref()));
+            let joints = gltf_skin.joints().len();
+            // Each joint's inverse bind matrix is the identity matrix when they're left out
+            let mut inverse_bindposes: Vec<Mat4> = match reader.read_inverse_bind_matrices() {
+                Some(matrices) => matrices.map(|mat| Mat4::from_cols_array_2d(&mat)).collect(),
+                None => vec![Mat4::IDENTITY; joints],
+            };
+            // The accessor may hold more matrices than there are joints, leaving the rest unused
+            if inverse_bindposes.len() < joints {
+                return Err(GltfError::InverseBindMatrixCountMismatch {
+                    skin: gltf_skin.index(),
+                    matrices: inverse_bindposes.len(),
+                    joints,
+                });
+            }
+            inverse_bindposes.truncate(joints);
 
-            load_context.set_labeled_asset(
+            let handle = load_context.set_labeled_asset(
                 &skin_label(&gltf_skin),
-                LoadedAsset::new(SkinnedMeshInverseBindposes::from(inverse_bindposes)),
-            )
+                LoadedAsset::new(SkinnedMeshInverseBindposes::from(inverse_bindposes.clone())),
+            );
+            Ok((handle, inverse_bindposes))
         })
-        .collect();
+        .collect::<Result<_, GltfError>>()?;
 
     let mut scenes = vec![];
     let mut named_scenes = HashMap::default();
+    let info = asset_info(&gltf);
     let mut active_camera_found = false;
-    for scene in gltf.scenes() {
-        let mut err = None;
-        let mut world = World::default();
-        let mut node_index_to_entity_map = HashMap::new();
-        let mut entity_to_skin_index_map = HashMap::new();
-
-        world
-            .spawn(SpatialBundle::INHERITED_IDENTITY)
-            .with_children(|parent| {
+    if asset_kinds.scenes {
+        for scene in gltf.scenes() {
+            let mut err = None;
+            let mut world = World::default();
+            let mut node_index_to_entity_map = HashMap::new();
+            let mut entity_to_skinned_primitive_map = HashMap::new();
+
+            let mut scene_root = world.spawn(SpatialBundle::INHERITED_IDENTITY);
+            scene_root.insert(info.clone());
+            if let Some(extras) = get_gltf_extras(scene.extras()) {
+                scene_root.insert(GltfSceneExtras(extras));
+            }
+            scene_root.with_children(|parent| {
                 for node in scene.nodes() {
                     let result = load_node(
                         &node,
                         parent,
+                        asset_kinds.materials.then_some(&texture_labels),
                         load_context,
                         &mut node_index_to_entity_map,
-                        &mut entity_to_skin_index_map,
+                        &mut entity_to_skinned_primitive_map,
                         &mut active_camera_found,
                     );
                     if result.is_err() {
@@ -737,44 +1605,133 @@ async fn load_gltf<'a, 'b>(
                     }
                 }
             });
-        if let Some(Err(err)) = err {
-            return Err(err);
-        }
+            if let Some(Err(err)) = err {
+                return Err(err);
+            }
 
-        #[cfg(feature = "bevy_animation")]
-        {
-            // for each node root in a scene, check if it's the root of an animation
-            // if it is, add the AnimationPlayer component
-            for node in scene.nodes() {
-                if animation_roots.contains(&node.index()) {
-                    world
-                        .entity_mut(*node_index_to_entity_map.get(&node.index()).unwrap())
-                        .insert(bevy_animation::AnimationPlayer::default());
+            #[cfg(feature = "bevy_animation")]
+            {
+                // for each node root in a scene, check if it's the root of an animation
+                // if it is, add the AnimationPlayer component
+                for node in scene.nodes() {
+                    if animation_roots.contains(&node.index()) {
+                        world
+                            .entity_mut(*node_index_to_entity_map.get(&node.index()).unwrap())
+                            .insert(bevy_animation::AnimationPlayer::default());
+                    }
                 }
             }
-        }
 
-        for (&entity, &skin_index) in &entity_to_skin_index_map {
-            let mut entity = world.entity_mut(entity);
-            let skin = gltf.skins().nth(skin_index).unwrap();
-            let joint_entities: Vec<_> = skin
-                .joints()
-                .map(|node| node_index_to_entity_map[&node.index()])
-                .collect();
+            if asset_kinds.skins {
+                for (&entity, skinned) in &entity_to_skinned_primitive_map {
+                    let skin = gltf.skins().nth(skinned.skin).unwrap();
+                    let joint_entities = skin
+                        .joints()
+                        .map(|node| {
+                            node_index_to_entity_map.get(&node.index()).copied().ok_or(
+                                GltfError::MissingJointNode {
+                                    skin: skinned.skin,
+                                    node: node.index(),
+                                },
+                            )
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+                    let (inverse_bindposes, inverse_bindpose_matrices) =
+                        &skinned_mesh_inverse_bindposes[skinned.skin];
 
-            entity.insert(SkinnedMesh {
-                inverse_bindposes: skinned_mesh_inverse_bindposes[skin_index].clone(),
-                joints: joint_entities,
-            });
-        }
+                    let partitions = mesh_partitions
+                        .get(&(skinned.mesh, skinned.primitive))
+                        .filter(|_| joint_entities.len() > MAX_JOINTS);
+                    let Some(partitions) = partitions else {
+                        world.entity_mut(entity).insert(SkinnedMesh {
+                            inverse_bindposes: inverse_bindposes.clone(),
+                            joints: joint_entities,
+                        });
+                        continue;
+                    };
+
+                    // The primitive's entity keeps its other components, with a child entity
+                    // for each part in place of its mesh
+                    let material = world
+                        .get::<Handle<StandardMaterial>>(entity)
+                        .cloned()
+                        .unwrap_or_default();
+                    let aabb = world.get::<Aabb>(entity).copied();
+                    world.entity_mut(entity).remove::<Handle<Mesh>>();
+                    for (index, (mesh, joints)) in partitions.iter().enumerate() {
+                        let joint_index_out_of_range = || GltfError::JointIndexOutOfRange {
+                            skin: skinned.skin,
+                            mesh: skinned.mesh,
+                            primitive: skinned.primitive,
+                        };
+                        let partition_joints = joints
+                            .iter()
+                            .map(|&joint| joint_entities.get(joint as usize).copied())
+                            .collect::<Option<Vec<_>>>()
+                            .ok_or_else(joint_index_out_of_range)?;
+                        let partition_matrices = joints
+                            .iter()
+                            .map(|&joint| inverse_bindpose_matrices[joint as usize])
+                            .collect::<Vec<_>>();
+                        let label =
+                            partition_skin_label(&skin, skinned.mesh, skinned.primitive, index);
+                        let partition_inverse_bindposes = if load_context.has_labeled_asset(&label)
+                        {
+                            load_context
+                                .get_handle(AssetPath::new_ref(load_context.path(), Some(&label)))
+                        } else {
+                            load_context.set_labeled_asset(
+                                &label,
+                                LoadedAsset::new(SkinnedMeshInverseBindposes::from(
+                                    partition_matrices,
+                                )),
+                            )
+                        };
 
-        let scene_handle = load_context
-            .set_labeled_asset(&scene_label(&scene), LoadedAsset::new(Scene::new(world)));
+                        let mut part = world.spawn((
+                            PbrBundle {
+                                mesh: mesh.clone(),
+                                material: material.clone(),
+                                ..Default::default()
+                            },
+                            SkinnedMesh {
+                                inverse_bindposes: partition_inverse_bindposes,
+                                joints: partition_joints,
+                            },
+                        ));
+                        if let Some(aabb) = aabb {
+                            part.insert(aabb);
+                        }
+                        let part = part.id();
+                        world.entity_mut(entity).push_children(&[part]);
+                    }
+                }
+            }
+
+            #[cfg(feature = "serde")]
+            if let Some(key) = &loader.extras_components_key {
+                insert_extras_components(
+                    &mut world,
+                    &node_index_to_entity_map,
+                    key,
+                    &loader.type_registry,
+                    &mut warnings,
+                )?;
+            }
+            #[cfg(feature = "serde")]
+            run_extras_handlers(&mut world, &loader.extras_handlers);
+            for processor in &loader.scene_post_processors {
+                processor.process(&mut world, &gltf, &scene, &node_index_to_entity_map);
+            }
+
+            let scene_handle = load_context
+                .set_labeled_asset(&scene_label(&scene), LoadedAsset::new(Scene::new(world)));
 
-        if let Some(name) = scene.name() {
-            named_scenes.insert(name.to_string(), scene_handle.clone());
+            if let Some(name) = scene.name() {
+                named_scenes.insert(name.to_string(), scene_handle.clone());
+            }
+            scenes.push(scene_handle);
         }
-        scenes.push(scene_handle);
     }
 
     load_context.set_default_asset(LoadedAsset::new(Gltf {
@@ -794,15 +1751,274 @@ async fn load_gltf<'a, 'b>(
         animations,
         #[cfg(feature = "bevy_animation")]
         named_animations,
+        warnings: warnings.list,
+        dependencies: external_files.dependencies(),
+        info,
+        scene_extras: gltf
+            .scenes()
+            .map(|scene| get_gltf_extras(scene.extras()))
+            .collect(),
     }));
 
     Ok(())
 }
 
+/// Decodes the vertex attributes and indices of a glTF primitive, generating normals and
+/// tangents where they are missing.
+fn load_primitive_mesh(
+    mesh_index: usize,
+    primitive: &Primitive,
+    buffer_data: &[Cow<'_, [u8]>],
+    loader: &GltfLoader,
+    warnings: &mut Warnings,
+) -> Result<Mesh, GltfError> {
+    let primitive_topology = get_primitive_topology(primitive.mode())?;
+
+    let mut mesh = Mesh::new(primitive_topology);
+
+    // Read vertex attributes
+    let mut joint_sets = BTreeMap::new();
+    let mut weight_sets = BTreeMap::new();
+    for (semantic, accessor) in primitive.attributes() {
+        // Joint attributes select Bevy's skinned mesh pipeline, which can't draw unskinned meshes
+        if !loader.asset_kinds.skins
+            && matches!(
+                semantic,
+                gltf::Semantic::Joints(_) | gltf::Semantic::Weights(_)
+            )
+        {
+            continue;
+        }
+        if let Some((attribute, conversion)) = match &semantic {
+            gltf::Semantic::Positions => {
+                Some((Mesh::ATTRIBUTE_POSITION, VertexAttributeConversion::Any))
+            }
+            gltf::Semantic::Normals => {
+                Some((Mesh::ATTRIBUTE_NORMAL, VertexAttributeConversion::Any))
+            }
+            gltf::Semantic::Tangents => {
+                Some((Mesh::ATTRIBUTE_TANGENT, VertexAttributeConversion::Any))
+            }
+            gltf::Semantic::Colors(0) => {
+                Some((Mesh::ATTRIBUTE_COLOR, VertexAttributeConversion::Rgba))
+            }
+            gltf::Semantic::TexCoords(0) => {
+                Some((Mesh::ATTRIBUTE_UV_0, VertexAttributeConversion::TexCoord))
+            }
+            gltf::Semantic::Joints(_) => Some((
+                Mesh::ATTRIBUTE_JOINT_INDEX,
+                VertexAttributeConversion::JointIndex,
+            )),
+            gltf::Semantic::Weights(_) => Some((
+                Mesh::ATTRIBUTE_JOINT_WEIGHT,
+                VertexAttributeConversion::JointWeight,
+            )),
+            gltf::Semantic::Extras(name) => loader
+                .custom_vertex_attributes
+                .get(name)
+                .map(|attr| (attr.clone(), VertexAttributeConversion::Any)),
+            _ => None,
+        } {
+            let raw_iter = VertexAttributeIter::from_accessor(accessor.clone(), buffer_data);
+            let converted_values = raw_iter.and_then(|iter| match conversion {
+                VertexAttributeConversion::Any => iter.into_any_values(),
+                VertexAttributeConversion::Rgba => iter.into_rgba_values(),
+                VertexAttributeConversion::TexCoord => iter.into_tex_coord_values(),
+                VertexAttributeConversion::JointIndex => iter.into_joint_index_values(),
+                VertexAttributeConversion::JointWeight => iter.into_joint_weight_values(),
+            });
+            match converted_values {
+                Ok(values) => {
+                    let loaded_format = VertexFormat::from(&values);
+                    if attribute.format == loaded_format {
+                        // Every set of joints and weights is combined once they've all been read
+                        match (&semantic, values) {
+                            (
+                                gltf::Semantic::Joints(set),
+                                VertexAttributeValues::Uint16x4(joints),
+                            ) => {
+                                joint_sets.insert(*set, joints);
+                            }
+                            (
+                                gltf::Semantic::Weights(set),
+                                VertexAttributeValues::Float32x4(weights),
+                            ) => {
+                                weight_sets.insert(*set, weights);
+                            }
+                            (_, values) => mesh.insert_attribute(attribute, values),
+                        }
+                    } else {
+                        warnings.push(GltfWarning::VertexAttributeFormatMismatch {
+                            mesh: mesh_index,
+                            primitive: primitive.index(),
+                            semantic,
+                            found: loaded_format,
+                            expected: attribute.format,
+                        })?;
+                    }
+                }
+                Err(AccessFailed::MalformedData) => {
+                    warnings.push(GltfWarning::MalformedVertexAttribute {
+                        mesh: mesh_index,
+                        primitive: primitive.index(),
+                        accessor: accessor.index(),
+                    })?;
+                }
+                Err(AccessFailed::UnsupportedFormat) => {
+                    warnings.push(GltfWarning::UnsupportedVertexAttributeFormat {
+                        mesh: mesh_index,
+                        primitive: primitive.index(),
+                        accessor: accessor.index(),
+                        data_type: accessor.data_type(),
+                        dimensions: accessor.dimensions(),
+                        normalized: accessor.normalized(),
+                    })?;
+                }
+            }
+        } else {
+            warnings.push(GltfWarning::UnrecognisedVertexAttribute {
+                mesh: mesh_index,
+                primitive: primitive.index(),
+                semantic,
+            })?;
+        }
+    }
+
+    let mut joint_influence_sets = vec![];
+    for (set, joints) in joint_sets {
+        match weight_sets.remove(&set) {
+            Some(weights) => joint_influence_sets.push((joints, weights)),
+            None => warnings.push(GltfWarning::UnpairedJointSet {
+                mesh: mesh_index,
+                primitive: primitive.index(),
+                set,
+            })?,
+        }
+    }
+    for set in weight_sets.into_keys() {
+        warnings.push(GltfWarning::UnpairedJointSet {
+            mesh: mesh_index,
+            primitive: primitive.index(),
+            set,
+        })?;
+    }
+    if !joint_influence_sets.is_empty() {
+        let vertex_count = joint_influence_sets[0].0.len();
+        if joint_influence_sets
+            .iter()
+            .any(|(joints, weights)| joints.len() != vertex_count || weights.len() != vertex_count)
+        {
+            return Err(GltfError::VertexCountMismatch {
+                mesh: mesh_index,
+                primitive: primitive.index(),
+            });
+        }
+        let mut groups =
+            reduce_joint_influences(&joint_influence_sets, loader.joint_influences.count())
+                .into_iter();
+        let attributes = [
+            (Mesh::ATTRIBUTE_JOINT_INDEX, Mesh::ATTRIBUTE_JOINT_WEIGHT),
+            (ATTRIBUTE_JOINT_INDEX_1, ATTRIBUTE_JOINT_WEIGHT_1),
+        ];
+        for ((joint_attribute, weight_attribute), (joints, weights)) in
+            attributes.into_iter().zip(&mut groups)
+        {
+            mesh.insert_attribute(joint_attribute, VertexAttributeValues::Uint16x4(joints));
+            mesh.insert_attribute(weight_attribute, weights);
+        }
+    }
+
+    // Read vertex indices
+    let reader =
+        primitive.reader(|buffer| buffer_data.get(buffer.index()).map(|data| data.as_ref()));
+    if let Some(indices) = reader.read_indices() {
+        mesh.set_indices(Some(match indices {
+            ReadIndices::U8(is) => Indices::U16(is.map(|x| x as u16).collect()),
+            ReadIndices::U16(is) => Indices::U16(is.collect()),
+            ReadIndices::U32(is) => Indices::U32(is.collect()),
+        }));
+    };
+
+    // Bevy panics on meshes whose attributes differ in length or whose indices are out of range
+    let vertex_counts = mesh
+        .attributes()
+        .map(|(_, values)| values.len())
+        .collect::<Vec<_>>();
+    let vertex_count = vertex_counts.first().copied().unwrap_or(0);
+    if vertex_counts.iter().any(|&count| count != vertex_count) {
+        return Err(GltfError::VertexCountMismatch {
+            mesh: mesh_index,
+            primitive: primitive.index(),
+        });
+    }
+    if let Some(indices) = mesh.indices() {
+        if indices.iter().any(|index| index >= vertex_count) {
+            return Err(GltfError::VertexIndexOutOfRange {
+                mesh: mesh_index,
+                primitive: primitive.index(),
+            });
+        }
+    }
+
+    let triangle_vertex_count = mesh.indices().map_or(vertex_count, Indices::len);
+    if mesh.attribute(Mesh::ATTRIBUTE_NORMAL).is_none()
+        && mesh.attribute(Mesh::ATTRIBUTE_POSITION).is_some()
+        && triangle_vertex_count.is_multiple_of(3)
+        && matches!(mesh.primitive_topology(), PrimitiveTopology::TriangleList)
+    {
+        let vertex_count_before = mesh.count_vertices();
+        mesh.duplicate_vertices();
+        mesh.compute_flat_normals();
+        let vertex_count_after = mesh.count_vertices();
+
+        if vertex_count_before != vertex_count_after {
+            bevy::log::debug!("Missing vertex normals in indexed geometry, computing them as flat. Vertex count increased from {} to {}", vertex_count_before, vertex_count_after);
+        } else {
+            bevy::log::debug!(
+                "Missing vertex normals in indexed geometry, computing them as flat."
+            );
+        }
+    }
+
+    // Tangents were read with the other attributes, which checks their format
+    if mesh.attribute(Mesh::ATTRIBUTE_TANGENT).is_none()
+        && loader.asset_kinds.materials
+        && mesh.attribute(Mesh::ATTRIBUTE_NORMAL).is_some()
+        && primitive.material().normal_texture().is_some()
+    {
+        // Tangents are only used by normal maps, which aren't loaded without materials
+        bevy::log::debug!("Missing vertex tangents, computing them using the mikktspace algorithm");
+        if let Err(err) = mesh.generate_tangents() {
+            warnings.push(GltfWarning::TangentGenerationFailed {
+                mesh: mesh_index,
+                primitive: primitive.index(),
+                message: err.to_string(),
+            })?;
+        }
+    }
+
+    Ok(mesh)
+}
+
+/// Collects the metadata of a glTF file from its `asset` and top level properties.
+fn asset_info(gltf: &gltf::Document) -> GltfAssetInfo {
+    let root = gltf.as_json();
+    GltfAssetInfo {
+        version: root.asset.version.clone(),
+        min_version: root.asset.min_version.clone(),
+        generator: root.asset.generator.clone(),
+        copyright: root.asset.copyright.clone(),
+        extensions_used: gltf.extensions_used().map(str::to_string).collect(),
+        extensions_required: gltf.extensions_required().map(str::to_string).collect(),
+        asset_extras: get_gltf_extras(&root.asset.extras),
+        extras: get_gltf_extras(&root.extras),
+    }
+}
+
 fn get_gltf_extras(extras: &gltf::json::Extras) -> Option<GltfExtras> {
-    extras.as_ref().map(|extras| super::GltfExtras {
-        value: extras.get().to_string(),
-    })
+    extras
+        .as_ref()
+        .map(|extras| GltfExtras::new(extras.get().to_string()))
 }
 
 fn node_name(node: &Node) -> Name {
@@ -828,103 +2044,314 @@ fn paths_recur(
     paths.insert(node.index(), (root_index, path));
 }
 
-/// Loads a glTF texture as a bevy [`Image`] and returns it together with its label.
-async fn load_texture<'a>(
-    gltf_texture: gltf::Texture<'a>,
-    buffer_data: &[Vec<u8>],
-    linear_textures: &HashSet<usize>,
+/// The labels of the image assets which glTF textures are loaded as, by usage. This is empty when
+/// textures aren't loaded.
+#[derive(Default)]
+struct TextureLabels(HashMap<(usize, ImageUsage), String>);
+
+impl TextureLabels {
+    fn get(&self, texture: &gltf::Texture, usage: ImageUsage) -> Option<&str> {
+        self.0.get(&(texture.index(), usage)).map(String::as_str)
+    }
+}
+
+/// The ways a glTF image can be used, each of which it is loaded as a separate image for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum ImageUsage {
+    /// Color data in sRGB color space.
+    Color,
+    /// Non-color data in linear color space.
+    Data,
+    /// A normal map in linear color space, whose mipmaps are renormalized.
+    NormalMap,
+}
+
+impl ImageUsage {
+    fn for_role(role: TextureRole) -> Self {
+        match role {
+            TextureRole::BaseColor | TextureRole::Emissive => ImageUsage::Color,
+            TextureRole::Normal => ImageUsage::NormalMap,
+            TextureRole::MetallicRoughness | TextureRole::Occlusion => ImageUsage::Data,
+        }
+    }
+}
+
+/// Identifies a glTF image loaded for a particular usage.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct ImageKey {
+    image: usize,
+    usage: ImageUsage,
+}
+
+/// Decodes a glTF image once and makes it into a bevy [`Image`] without a sampler for each of
+/// its `uses`, given as how it's used and the roles it's used in, limiting its size and
+/// generating mipmaps as configured for those roles. Returns the images in the order of `uses`
+/// with any warnings raised while processing them.
+async fn load_image<'a>(
+    gltf_image: gltf::Image<'a>,
+    uses: &[(ImageUsage, &[TextureRole])],
+    buffer_data: &[Cow<'_, [u8]>],
     load_context: &LoadContext<'a>,
-    supported_compressed_formats: CompressedImageFormats,
-) -> Result<(Image, String), GltfError> {
-    let is_srgb = !linear_textures.contains(&gltf_texture.index());
-    let mut texture = match gltf_texture.source().source() {
+    loader: &GltfLoader,
+    external_files: &ExternalFiles,
+) -> Result<(Vec<Image>, Warnings), GltfError> {
+    let mut warnings = Warnings::new(loader.strict);
+    let is_srgb = uses.iter().any(|(usage, _)| *usage == ImageUsage::Color);
+    let decoded = match gltf_image.source() {
         gltf::image::Source::View { view, mime_type } => {
-            let start = view.offset();
-            let end = view.offset() + view.length();
-            let buffer = &buffer_data[view.buffer().index()][start..end];
+            let buffer = buffer_view_data(&view, buffer_data)
+                .ok_or(GltfError::BufferViewOutOfRange { view: view.index() })?;
+            check_encoded_image_size(buffer, &loader.limits)?;
             Image::from_buffer(
                 buffer,
                 ImageType::MimeType(mime_type),
-                supported_compressed_formats,
+                loader.supported_compressed_formats,
                 is_srgb,
             )?
         }
         gltf::image::Source::Uri { uri, mime_type } => {
             let uri = percent_encoding::percent_decode_str(uri)
                 .decode_utf8()
-                .unwrap();
+                .map_err(|_| GltfError::InvalidImageUri {
+                    image: gltf_image.index(),
+                })?;
             let uri = uri.as_ref();
-            let (bytes, image_type) = if let Ok(data_uri) = DataUri::parse(uri) {
-                (data_uri.decode()?, ImageType::MimeType(data_uri.mime_type))
+            let (bytes, data_uri_mime_type, image_path) = if let Ok(data_uri) = DataUri::parse(uri)
+            {
+                (
+                    Cow::Owned(data_uri.decode()?),
+                    Some(data_uri.mime_type),
+                    None,
+                )
             } else {
-                let parent = load_context.path().parent().unwrap();
-                let image_path = parent.join(uri);
-                let bytes = load_context.read_asset_bytes(image_path.clone()).await?;
-
-                let extension = Path::new(uri).extension().unwrap().to_str().unwrap();
-                let image_type = ImageType::Extension(extension);
-
-                (bytes, image_type)
+                let (bytes, image_path) = external_files
+                    .read(uri, UriRole::Image, load_context, loader)
+                    .await?;
+                (bytes, None, image_path)
+            };
+            let image_type = match mime_type.or(data_uri_mime_type) {
+                Some(mime_type) => ImageType::MimeType(mime_type),
+                None => {
+                    // Resolved paths, such as content-addressed ones, may have no extension, so
+                    // fall back to the URI's extension and then to the image's contents
+                    let extension = image_path
+                        .as_deref()
+                        .into_iter()
+                        .chain([Path::new(uri)])
+                        .find_map(|path| path.extension()?.to_str())
+                        .or_else(|| {
+                            let format = image::guess_format(&bytes).ok()?;
+                            format.extensions_str().first().copied()
+                        })
+                        .ok_or(GltfError::UnknownImageFormat {
+                            image: gltf_image.index(),
+                        })?;
+                    ImageType::Extension(extension)
+                }
             };
 
+            check_encoded_image_size(&bytes, &loader.limits)?;
             Image::from_buffer(
                 &bytes,
-                mime_type.map(ImageType::MimeType).unwrap_or(image_type),
-                supported_compressed_formats,
+                image_type,
+                loader.supported_compressed_formats,
                 is_srgb,
             )?
         }
     };
-    texture.sampler_descriptor = ImageSampler::Descriptor(texture_sampler(&gltf_texture));
+    let size = decoded.texture_descriptor.size;
+    check_limit(
+        GltfLimit::TextureDimension,
+        loader.limits.max_texture_dimension.map(|max| max as usize),
+        size.width.max(size.height) as usize,
+    )?;
+
+    let mut images = vec![];
+    let mut mipmaps_generated = true;
+    for &(usage, roles) in uses {
+        let mut image = decoded.clone();
+        let format = image.texture_descriptor.format;
+        image.texture_descriptor.format = if usage == ImageUsage::Color {
+            format.add_srgb_suffix()
+        } else {
+            format.remove_srgb_suffix()
+        };
+        let is_normal_map = usage == ImageUsage::NormalMap;
+        if let Some(max_dimension) = loader.texture_size_limits.max_dimension(roles) {
+            if !limit_size(&mut image, max_dimension, is_normal_map) {
+                warnings.push(GltfWarning::ImageNotDownscaled {
+                    image: gltf_image.index(),
+                    max_dimension,
+                })?;
+            }
+        }
+        if loader.generate_mipmaps {
+            mipmaps_generated &= generate_mipmaps(&mut image, is_normal_map);
+        }
+        images.push(image);
+    }
+    if !mipmaps_generated {
+        warnings.push(GltfWarning::MipmapsNotGenerated {
+            image: gltf_image.index(),
+        })?;
+    }
+
+    Ok((images, warnings))
+}
+
+/// Applies the [`TextureErrorPolicy`] to an image which failed to load with `err`. Returns the
+/// warning to raise with a placeholder for each of its `image_keys`, or `None` if the textures
+/// using it are left out. Exceeded limits and images which can't be downscaled in strict mode fail
+/// the load as they are.
+fn failed_image_placeholders(
+    image: usize,
+    image_keys: &[ImageKey],
+    err: GltfError,
+    loader: &GltfLoader,
+) -> Result<(GltfWarning, Option<Vec<Image>>), GltfError> {
+    if loader.texture_error_policy == TextureErrorPolicy::Fail
+        || matches!(
+            err,
+            GltfError::LimitExceeded { .. } | GltfError::ImageNotDownscaled { .. }
+        )
+    {
+        return Err(err);
+    }
+    let warning = GltfWarning::ImageLoadFailed {
+        image,
+        message: err.to_string(),
+    };
+    let placeholders =
+        (loader.texture_error_policy == TextureErrorPolicy::Placeholder).then(|| {
+            image_keys
+                .iter()
+                .map(|image_key| placeholder_image(image_key.usage))
+                .collect()
+        });
+    Ok((warning, placeholders))
+}
+
+/// Checks the dimensions of an encoded image against the texture dimension limit before it is
+/// decoded, if its format is recognised from its header.
+fn check_encoded_image_size(bytes: &[u8], limits: &GltfLimits) -> Result<(), GltfError> {
+    let Some(max_dimension) = limits.max_texture_dimension else {
+        return Ok(());
+    };
+    let dimensions = image::io::Reader::new(io::Cursor::new(bytes))
+        .with_guessed_format()
+        .ok()
+        .and_then(|reader| reader.into_dimensions().ok());
+    match dimensions {
+        Some((width, height)) => check_limit(
+            GltfLimit::TextureDimension,
+            Some(max_dimension as usize),
+            width.max(height) as usize,
+        ),
+        None => Ok(()),
+    }
+}
+
+/// Returns the textures used by a glTF material together with the roles they are used in.
+fn material_textures<'a>(material: &Material<'a>) -> Vec<(gltf::Texture<'a>, TextureRole)> {
+    let pbr = material.pbr_metallic_roughness();
+    [
+        pbr.base_color_texture()
+            .map(|info| (info.texture(), TextureRole::BaseColor)),
+        material
+            .normal_texture()
+            .map(|info| (info.texture(), TextureRole::Normal)),
+        pbr.metallic_roughness_texture()
+            .map(|info| (info.texture(), TextureRole::MetallicRoughness)),
+        material
+            .occlusion_texture()
+            .map(|info| (info.texture(), TextureRole::Occlusion)),
+        material
+            .emissive_texture()
+            .map(|info| (info.texture(), TextureRole::Emissive)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
 
-    Ok((texture, texture_label(&gltf_texture)))
+/// Creates an image to stand in for one which couldn't be loaded: a magenta checkerboard for color
+/// data, plain white for other non-color data and a flat normal map.
+fn placeholder_image(usage: ImageUsage) -> Image {
+    const SIZE: u32 = 8;
+    let (format, texels): (_, [[u8; 4]; 2]) = match usage {
+        ImageUsage::Color => (
+            TextureFormat::Rgba8UnormSrgb,
+            [[255, 0, 255, 255], [0, 0, 0, 255]],
+        ),
+        ImageUsage::Data => (TextureFormat::Rgba8Unorm, [[255; 4]; 2]),
+        ImageUsage::NormalMap => (TextureFormat::Rgba8Unorm, [[128, 128, 255, 255]; 2]),
+    };
+    let data = (0..SIZE)
+        .flat_map(|y| {
+            (0..SIZE).flat_map(move |x| texels[((x * 2 / SIZE + y * 2 / SIZE) % 2) as usize])
+        })
+        .collect();
+    Image::new(
+        Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        format,
+    )
 }
 
 /// Loads a glTF material as a bevy [`StandardMaterial`] and returns it.
-fn load_material(material: &Material, load_context: &mut LoadContext) -> Handle<StandardMaterial> {
+fn load_material(
+    material: &Material,
+    texture_labels: &TextureLabels,
+    load_context: &mut LoadContext,
+) -> Handle<StandardMaterial> {
     let material_label = material_label(material);
 
     let pbr = material.pbr_metallic_roughness();
 
     let color = pbr.base_color_factor();
-    let base_color_texture = pbr.base_color_texture().map(|info| {
+    let base_color_texture = pbr.base_color_texture().and_then(|info| {
         // TODO: handle info.tex_coord() (the *set* index for the right texcoords)
-        let label = texture_label(&info.texture());
-        let path = AssetPath::new_ref(load_context.path(), Some(&label));
-        load_context.get_handle(path)
+        let label = texture_labels.get(&info.texture(), ImageUsage::Color)?;
+        let path = AssetPath::new_ref(load_context.path(), Some(label));
+        Some(load_context.get_handle(path))
     });
 
     let normal_map_texture: Option<Handle<Image>> =
-        material.normal_texture().map(|normal_texture| {
+        material.normal_texture().and_then(|normal_texture| {
             // TODO: handle normal_texture.scale
             // TODO: handle normal_texture.tex_coord() (the *set* index for the right texcoords)
-            let label = texture_label(&normal_texture.texture());
-            let path = AssetPath::new_ref(load_context.path(), Some(&label));
-            load_context.get_handle(path)
+            let label = texture_labels.get(&normal_texture.texture(), ImageUsage::NormalMap)?;
+            let path = AssetPath::new_ref(load_context.path(), Some(label));
+            Some(load_context.get_handle(path))
         });
 
-    let metallic_roughness_texture = pbr.metallic_roughness_texture().map(|info| {
+    let metallic_roughness_texture = pbr.metallic_roughness_texture().and_then(|info| {
         // TODO: handle info.tex_coord() (the *set* index for the right texcoords)
-        let label = texture_label(&info.texture());
-        let path = AssetPath::new_ref(load_context.path(), Some(&label));
-        load_context.get_handle(path)
+        let label = texture_labels.get(&info.texture(), ImageUsage::Data)?;
+        let path = AssetPath::new_ref(load_context.path(), Some(label));
+        Some(load_context.get_handle(path))
     });
 
-    let occlusion_texture = material.occlusion_texture().map(|occlusion_texture| {
+    let occlusion_texture = material.occlusion_texture().and_then(|occlusion_texture| {
         // TODO: handle occlusion_texture.tex_coord() (the *set* index for the right texcoords)
         // TODO: handle occlusion_texture.strength() (a scalar multiplier for occlusion strength)
-        let label = texture_label(&occlusion_texture.texture());
-        let path = AssetPath::new_ref(load_context.path(), Some(&label));
-        load_context.get_handle(path)
+        let label = texture_labels.get(&occlusion_texture.texture(), ImageUsage::Data)?;
+        let path = AssetPath::new_ref(load_context.path(), Some(label));
+        Some(load_context.get_handle(path))
     });
 
     let emissive = material.emissive_factor();
-    let emissive_texture = material.emissive_texture().map(|info| {
+    let emissive_texture = material.emissive_texture().and_then(|info| {
         // TODO: handle occlusion_texture.tex_coord() (the *set* index for the right texcoords)
         // TODO: handle occlusion_texture.strength() (a scalar multiplier for occlusion strength)
-        let label = texture_label(&info.texture());
-        let path = AssetPath::new_ref(load_context.path(), Some(&label));
-        load_context.get_handle(path)
+        let label = texture_labels.get(&info.texture(), ImageUsage::Color)?;
+        let path = AssetPath::new_ref(load_context.path(), Some(label));
+        Some(load_context.get_handle(path))
     });
 
     load_context.set_labeled_asset(
@@ -952,13 +2379,108 @@ fn load_material(material: &Material, load_context: &mut LoadContext) -> Handle<
     )
 }
 
-/// Loads a glTF node.
+/// Inserts the components in the `key` of the [`GltfExtras`] of each node spawned in a scene into
+/// its entity. The key holds a map from the full or short type names of components to their values,
+/// which are deserialized with the `type_registry`.
+#[cfg(feature = "serde")]
+fn insert_extras_components(
+    world: &mut World,
+    node_index_to_entity_map: &HashMap<usize, Entity>,
+    key: &str,
+    type_registry: &AppTypeRegistry,
+    warnings: &mut Warnings,
+) -> Result<(), GltfError> {
+    use bevy::reflect::serde::TypedReflectDeserializer;
+    use serde::de::DeserializeSeed;
+
+    let type_registry = type_registry.read();
+    let mut nodes = node_index_to_entity_map.iter().collect::<Vec<_>>();
+    nodes.sort_unstable_by_key(|(&node, _)| node);
+    for (&node, &entity) in nodes {
+        let Some(extras) = world.get::<GltfExtras>(entity) else {
+            continue;
+        };
+        let components = match extras.json().and_then(|json| json.get(key)) {
+            Some(serde_json::Value::Object(components)) => components.clone(),
+            Some(_) => {
+                warnings.push(GltfWarning::InvalidExtrasComponents {
+                    node,
+                    key: key.to_string(),
+                })?;
+                continue;
+            }
+            None => continue,
+        };
+
+        for (component, value) in &components {
+            let failed = |message: &str| GltfWarning::ExtrasComponentFailed {
+                node,
+                component: component.clone(),
+                message: message.to_string(),
+            };
+            let Some(registration) = type_registry
+                .get_with_name(component)
+                .or_else(|| type_registry.get_with_short_name(component))
+            else {
+                warnings.push(failed("type isn't registered"))?;
+                continue;
+            };
+            let Some(reflect_component) = registration.data::<ReflectComponent>() else {
+                warnings.push(failed("type isn't a reflected component"))?;
+                continue;
+            };
+            match TypedReflectDeserializer::new(registration, &type_registry).deserialize(value) {
+                Ok(reflected) => {
+                    reflect_component.insert(&mut world.entity_mut(entity), &*reflected)
+                }
+                Err(err) => warnings.push(failed(&err.to_string()))?,
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Calls the handler registered for each property of the extras of every entity in a scene, in
+/// the order the entities were spawned.
+#[cfg(feature = "serde")]
+fn run_extras_handlers(world: &mut World, handlers: &HashMap<String, Arc<ExtrasHandler>>) {
+    if handlers.is_empty() {
+        return;
+    }
+    let mut entities = world
+        .query::<(Entity, &GltfExtras)>()
+        .iter(world)
+        .filter_map(|(entity, extras)| match extras.json() {
+            Some(serde_json::Value::Object(properties)) => Some((entity, properties.clone())),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    entities.sort_unstable_by_key(|(entity, _)| *entity);
+    for (entity, properties) in entities {
+        for (property, value) in &properties {
+            if let Some(handler) = handlers.get(property) {
+                handler(value, &mut world.entity_mut(entity));
+            }
+        }
+    }
+}
+
+/// A mesh entity which is given a [`SkinnedMesh`] once every node of its scene has been spawned.
+struct SkinnedPrimitive {
+    skin: usize,
+    mesh: usize,
+    primitive: usize,
+}
+
+/// Loads a glTF node. Meshes use the default material when `texture_labels` is `None` because
+/// materials aren't loaded.
 fn load_node(
     gltf_node: &gltf::Node,
     world_builder: &mut WorldChildBuilder,
+    texture_labels: Option<&TextureLabels>,
     load_context: &mut LoadContext,
     node_index_to_entity_map: &mut HashMap<usize, Entity>,
-    entity_to_skin_index_map: &mut HashMap<Entity, usize>,
+    entity_to_skinned_primitive_map: &mut HashMap<Entity, SkinnedPrimitive>,
     active_camera_found: &mut bool,
 ) -> Result<(), GltfError> {
     let transform = gltf_node.transform();
@@ -968,10 +2490,8 @@ fn load_node(
 
     node.insert(node_name(gltf_node));
 
-    if let Some(extras) = gltf_node.extras() {
-        node.insert(super::GltfExtras {
-            value: extras.get().to_string(),
-        });
+    if let Some(extras) = get_gltf_extras(gltf_node.extras()) {
+        node.insert(extras);
     }
 
     // create camera node
@@ -1025,25 +2545,31 @@ fn load_node(
             // append primitives
             for primitive in mesh.primitives() {
                 let material = primitive.material();
-                let material_label = material_label(&material);
+                let material_handle = if let Some(texture_labels) = texture_labels {
+                    let material_label = material_label(&material);
 
-                // This will make sure we load the default material now since it would not have been
-                // added when iterating over all the gltf materials (since the default material is
-                // not explicitly listed in the gltf).
-                if !load_context.has_labeled_asset(&material_label) {
-                    load_material(&material, load_context);
-                }
+                    // This will make sure we load the default material now since it would not have been
+                    // added when iterating over all the gltf materials (since the default material is
+                    // not explicitly listed in the gltf).
+                    if !load_context.has_labeled_asset(&material_label) {
+                        load_material(&material, texture_labels, load_context);
+                    }
+
+                    let material_asset_path =
+                        AssetPath::new_ref(load_context.path(), Some(&material_label));
+                    load_context.get_handle(material_asset_path)
+                } else {
+                    Handle::default()
+                };
 
                 let primitive_label = primitive_label(&mesh, &primitive);
                 let bounds = primitive.bounding_box();
                 let mesh_asset_path =
                     AssetPath::new_ref(load_context.path(), Some(&primitive_label));
-                let material_asset_path =
-                    AssetPath::new_ref(load_context.path(), Some(&material_label));
 
                 let mut mesh_entity = parent.spawn(PbrBundle {
                     mesh: load_context.get_handle(mesh_asset_path),
-                    material: load_context.get_handle(material_asset_path),
+                    material: material_handle,
                     ..Default::default()
                 });
                 mesh_entity.insert(Aabb::from_min_max(
@@ -1051,17 +2577,28 @@ fn load_node(
                     Vec3::from_slice(&bounds.max),
                 ));
 
-                if let Some(extras) = primitive.extras() {
-                    mesh_entity.insert(super::GltfExtras {
-                        value: extras.get().to_string(),
-                    });
+                if let Some(extras) = get_gltf_extras(primitive.extras()) {
+                    mesh_entity.insert(extras);
+                }
+                if let Some(extras) = get_gltf_extras(mesh.extras()) {
+                    mesh_entity.insert(GltfMeshExtras(extras));
+                }
+                if let Some(extras) = get_gltf_extras(material.extras()) {
+                    mesh_entity.insert(GltfMaterialExtras(extras));
                 }
                 if let Some(name) = mesh.name() {
                     mesh_entity.insert(Name::new(name.to_string()));
                 }
                 // Mark for adding skinned mesh
                 if let Some(skin) = gltf_node.skin() {
-                    entity_to_skin_index_map.insert(mesh_entity.id(), skin.index());
+                    entity_to_skinned_primitive_map.insert(
+                        mesh_entity.id(),
+                        SkinnedPrimitive {
+                            skin: skin.index(),
+                            mesh: mesh.index(),
+                            primitive: primitive.index(),
+                        },
+                    );
                 }
             }
         }
@@ -1082,10 +2619,8 @@ fn load_node(
                     if let Some(name) = light.name() {
                         entity.insert(Name::new(name.to_string()));
                     }
-                    if let Some(extras) = light.extras() {
-                        entity.insert(super::GltfExtras {
-                            value: extras.get().to_string(),
-                        });
+                    if let Some(extras) = get_gltf_extras(light.extras()) {
+                        entity.insert(extras);
                     }
                 }
                 gltf::khr_lights_punctual::Kind::Point => {
@@ -1105,10 +2640,8 @@ fn load_node(
                     if let Some(name) = light.name() {
                         entity.insert(Name::new(name.to_string()));
                     }
-                    if let Some(extras) = light.extras() {
-                        entity.insert(super::GltfExtras {
-                            value: extras.get().to_string(),
-                        });
+                    if let Some(extras) = get_gltf_extras(light.extras()) {
+                        entity.insert(extras);
                     }
                 }
                 gltf::khr_lights_punctual::Kind::Spot {
@@ -1133,10 +2666,8 @@ fn load_node(
                     if let Some(name) = light.name() {
                         entity.insert(Name::new(name.to_string()));
                     }
-                    if let Some(extras) = light.extras() {
-                        entity.insert(super::GltfExtras {
-                            value: extras.get().to_string(),
-                        });
+                    if let Some(extras) = get_gltf_extras(light.extras()) {
+                        entity.insert(extras);
                     }
                 }
             }
@@ -1147,9 +2678,10 @@ fn load_node(
             if let Err(err) = load_node(
                 &child,
                 parent,
+                texture_labels,
                 load_context,
                 node_index_to_entity_map,
-                entity_to_skin_index_map,
+                entity_to_skinned_primitive_map,
                 active_camera_found,
             ) {
                 gltf_error = Some(err);
@@ -1174,6 +2706,12 @@ fn primitive_label(mesh: &gltf::Mesh, primitive: &Primitive) -> String {
     format!("Mesh{}/Primitive{}", mesh.index(), primitive.index())
 }
 
+/// Returns the label for a part of the primitive labelled `primitive_label` which uses fewer
+/// joints than its skin has.
+fn partition_label(primitive_label: &str, partition: usize) -> String {
+    format!("{primitive_label}/Partition{partition}")
+}
+
 /// Returns the label for the `material`.
 fn material_label(material: &gltf::Material) -> String {
     if let Some(index) = material.index() {
@@ -1188,6 +2726,23 @@ fn texture_label(texture: &gltf::Texture) -> String {
     format!("Texture{}", texture.index())
 }
 
+/// Returns the label for a copy of a glTF image. The copy for the first of the image's usages has
+/// no suffix for its usage, and a copy is made for each distinct sampler after the first.
+fn image_label(image_key: ImageKey, is_primary: bool, sampler_index: usize) -> String {
+    let mut label = format!("Image{}", image_key.image);
+    if !is_primary {
+        label.push_str(match image_key.usage {
+            ImageUsage::Color => "/Color",
+            ImageUsage::Data => "/Linear",
+            ImageUsage::NormalMap => "/NormalMap",
+        });
+    }
+    if sampler_index > 0 {
+        label.push_str(&format!("/Sampler{sampler_index}"));
+    }
+    label
+}
+
 /// Returns the label for the `node`.
 fn node_label(node: &gltf::Node) -> String {
     format!("Node{}", node.index())
@@ -1202,6 +2757,20 @@ fn skin_label(skin: &gltf::Skin) -> String {
     format!("Skin{}", skin.index())
 }
 
+/// Returns the label for the inverse bindposes of the `skin` for a part of a primitive which
+/// uses fewer joints than the skin has.
+fn partition_skin_label(
+    skin: &gltf::Skin,
+    mesh: usize,
+    primitive: usize,
+    partition: usize,
+) -> String {
+    format!(
+        "{}/Mesh{mesh}/Primitive{primitive}/Partition{partition}",
+        skin_label(skin)
+    )
+}
+
 /// Extracts the texture sampler data from the glTF texture.
 fn texture_sampler<'a>(texture: &gltf::Texture) -> SamplerDescriptor<'a> {
     let gltf_sampler = texture.sampler();
@@ -1276,53 +2845,467 @@ fn alpha_mode(material: &Material) -> AlphaMode {
     }
 }
 
-/// Loads the raw glTF buffer data for a specific glTF file.
-async fn load_buffers(
-    gltf: &gltf::Gltf,
-    load_context: &LoadContext<'_>,
-    asset_path: &Path,
-) -> Result<Vec<Vec<u8>>, GltfError> {
-    const VALID_MIME_TYPES: &[&str] = &["application/octet-stream", "application/gltf-buffer"];
+/// The binary chunk of a GLB file, borrowed from the bytes of the file.
+type Blob<'a> = Option<Cow<'a, [u8]>>;
 
-    let mut buffer_data = Vec::new();
-    for buffer in gltf.buffers() {
-        match buffer.source() {
-            gltf::buffer::Source::Uri(uri) => {
-                let uri = percent_encoding::percent_decode_str(uri)
-                    .decode_utf8()
-                    .unwrap();
-                let uri = uri.as_ref();
-                let buffer_bytes = match DataUri::parse(uri) {
-                    Ok(data_uri) if VALID_MIME_TYPES.contains(&data_uri.mime_type) => {
-                        data_uri.decode()?
+/// Parses a glTF or GLB file, returning the binary chunk of a GLB file without copying it.
+fn parse_gltf(bytes: &[u8]) -> Result<(gltf::Document, Blob<'_>), gltf::Error> {
+    if bytes.starts_with(b"glTF") {
+        let glb = gltf::Glb::from_slice(bytes)?;
+        let json = gltf::json::Root::from_slice(&glb.json)?;
+        Ok((validate_json(json)?, glb.bin))
+    } else {
+        let json = gltf::json::Root::from_slice(bytes)?;
+        Ok((validate_json(json)?, None))
+    }
+}
+
+/// Validates glTF JSON, first checking the `POSITION` accessor indices which the glTF crate's
+/// own validation uses without checking.
+fn validate_json(json: gltf::json::Root) -> Result<gltf::Document, gltf::Error> {
+    use gltf::json::{mesh::Semantic, validation::Checked, Path};
+
+    for (mesh_index, mesh) in json.meshes.iter().enumerate() {
+        for (primitive_index, primitive) in mesh.primitives.iter().enumerate() {
+            let position = primitive
+                .attributes
+                .get(&Checked::Valid(Semantic::Positions));
+            if position.is_some_and(|position| json.get(*position).is_none()) {
+                let path = Path::new()
+                    .field("meshes")
+                    .index(mesh_index)
+                    .field("primitives")
+                    .index(primitive_index)
+                    .field("attributes")
+                    .key("POSITION");
+                return Err(gltf::Error::Validation(vec![(
+                    path,
+                    gltf::json::validation::Error::IndexOutOfBounds,
+                )]));
+            }
+        }
+    }
+    gltf::Document::from_json(json)
+}
+
+/// Four joints influencing each vertex of a mesh and their weights.
+type JointInfluenceSet = (Vec<[u16; 4]>, Vec<[f32; 4]>);
+
+/// Reduces the joints influencing each vertex in every set of joints and weights to the `count`
+/// with the greatest weights, normalizing their weights to sum to one. Returns the joints and
+/// weights in groups of four. Every set must have the same number of vertices.
+fn reduce_joint_influences(sets: &[JointInfluenceSet], count: usize) -> Vec<JointInfluenceSet> {
+    let vertex_count = sets.first().map_or(0, |(joints, _)| joints.len());
+    let mut groups = vec![(vec![[0; 4]; vertex_count], vec![[0.0; 4]; vertex_count]); count / 4];
+    let mut influences = vec![];
+    for vertex in 0..vertex_count {
+        influences.clear();
+        for (joints, weights) in sets {
+            for (&joint, &weight) in joints[vertex].iter().zip(&weights[vertex]) {
+                // Also leaves out negative and NaN weights
+                if weight > 0.0 {
+                    influences.push((joint, weight));
+                }
+            }
+        }
+        influences.sort_by(|(_, a): &(u16, f32), (_, b)| b.total_cmp(a));
+        influences.truncate(count);
+        let total = influences.iter().map(|(_, weight)| weight).sum::<f32>();
+        for (i, &(joint, weight)) in influences.iter().enumerate() {
+            let (joints, weights) = &mut groups[i / 4];
+            joints[vertex][i % 4] = joint;
+            weights[vertex][i % 4] = weight / total;
+        }
+    }
+    groups
+}
+
+/// Loads the raw glTF buffer data for a specific glTF file.
+///
+/// The binary chunk of a GLB file and any buffers in the archive it was loaded from are borrowed
+/// rather than copied.
+async fn load_buffers<'a>(
+    gltf: &gltf::Document,
+    blob: Blob<'a>,
+    load_context: &LoadContext<'_>,
+    loader: &GltfLoader,
+    external_files: &'a ExternalFiles,
+) -> Result<Vec<Cow<'a, [u8]>>, GltfError> {
+    const VALID_MIME_TYPES: &[&str] = &["application/octet-stream", "application/gltf-buffer"];
+
+    let mut buffer_data = Vec::new();
+    let mut buffer_bytes_total = 0usize;
+    for buffer in gltf.buffers() {
+        match buffer.source() {
+            gltf::buffer::Source::Uri(uri) => {
+                let uri = percent_encoding::percent_decode_str(uri)
+                    .decode_utf8()
+                    .map_err(|_| GltfError::InvalidBufferUri {
+                        buffer: buffer.index(),
+                    })?;
+                let uri = uri.as_ref();
+                let buffer_bytes = match DataUri::parse(uri) {
+                    Ok(data_uri) if VALID_MIME_TYPES.contains(&data_uri.mime_type) => {
+                        Cow::Owned(data_uri.decode()?)
                     }
                     Ok(_) => return Err(GltfError::BufferFormatUnsupported),
                     Err(()) => {
-                        // TODO: Remove this and add dep
-                        let buffer_path = asset_path.parent().unwrap().join(uri);
-                        load_context.read_asset_bytes(buffer_path).await?
+                        external_files
+                            .read(uri, UriRole::Buffer, load_context, loader)
+                            .await?
+                            .0
                     }
                 };
                 buffer_data.push(buffer_bytes);
             }
             gltf::buffer::Source::Bin => {
-                if let Some(blob) = gltf.blob.as_deref() {
-                    buffer_data.push(blob.into());
+                if let Some(blob) = blob.clone() {
+                    buffer_data.push(blob);
                 } else {
                     return Err(GltfError::MissingBlob);
                 }
             }
         }
+        // Declared lengths were checked up front, but external buffers may be longer
+        buffer_bytes_total = buffer_bytes_total.saturating_add(buffer_data.last().unwrap().len());
+        check_limit(
+            GltfLimit::BufferBytes,
+            loader.limits.max_buffer_bytes,
+            buffer_bytes_total,
+        )?;
+    }
+
+    for (buffer, data) in gltf.buffers().zip(&buffer_data) {
+        if data.len() < buffer.length() {
+            return Err(GltfError::BufferTooShort {
+                buffer: buffer.index(),
+                length: data.len(),
+                expected: buffer.length(),
+            });
+        }
     }
 
     Ok(buffer_data)
 }
 
+/// Checks the resources a glTF file declares against `limits`, before any of its buffers are
+/// loaded. The node graph must have been validated.
+fn check_limits(gltf: &gltf::Document, limits: &GltfLimits) -> Result<(), GltfError> {
+    let buffer_bytes = gltf.buffers().fold(0usize, |total, buffer| {
+        total.saturating_add(buffer.length())
+    });
+    check_limit(
+        GltfLimit::BufferBytes,
+        limits.max_buffer_bytes,
+        buffer_bytes,
+    )?;
+
+    let (mut vertices, mut indices) = (0usize, 0usize);
+    for primitive in gltf.meshes().flat_map(|mesh| mesh.primitives()) {
+        let vertex_count = primitive
+            .attributes()
+            .map(|(_, accessor)| accessor.count())
+            .max()
+            .unwrap_or_default();
+        vertices = vertices.saturating_add(vertex_count);
+        if let Some(accessor) = primitive.indices() {
+            indices = indices.saturating_add(accessor.count());
+        }
+    }
+    check_limit(GltfLimit::Vertices, limits.max_vertices, vertices)?;
+    check_limit(GltfLimit::Indices, limits.max_indices, indices)?;
+
+    check_limit(
+        GltfLimit::Textures,
+        limits.max_textures,
+        gltf.textures().len(),
+    )?;
+    check_limit(GltfLimit::Nodes, limits.max_nodes, gltf.nodes().len())?;
+    if limits.max_node_depth.is_some() {
+        check_limit(
+            GltfLimit::NodeDepth,
+            limits.max_node_depth,
+            node_graph_depth(gltf),
+        )?;
+    }
+
+    let keyframes = gltf
+        .animations()
+        .flat_map(|animation| animation.channels())
+        .fold(0usize, |total, channel| {
+            total.saturating_add(channel.sampler().input().count())
+        });
+    check_limit(
+        GltfLimit::AnimationKeyframes,
+        limits.max_animation_keyframes,
+        keyframes,
+    )
+}
+
+/// Returns the length of the longest chain of nodes from parent to child in an acyclic node
+/// graph.
+fn node_graph_depth(gltf: &gltf::Document) -> usize {
+    // The depth below each node is memoized, as nodes may have several parents in a malformed
+    // file and following every path could take exponential time
+    let mut depths = vec![None; gltf.nodes().len()];
+    for root in gltf.nodes() {
+        let mut stack = vec![root];
+        while let Some(node) = stack.last() {
+            if depths[node.index()].is_some() {
+                stack.pop();
+                continue;
+            }
+            let pending = node
+                .children()
+                .filter(|child| depths[child.index()].is_none())
+                .collect::<Vec<_>>();
+            if pending.is_empty() {
+                let depth = 1 + node
+                    .children()
+                    .filter_map(|child| depths[child.index()])
+                    .max()
+                    .unwrap_or_default();
+                depths[node.index()] = Some(depth);
+                stack.pop();
+            } else {
+                stack.extend(pending);
+            }
+        }
+    }
+    depths.into_iter().flatten().max().unwrap_or_default()
+}
+
+/// Checks that every buffer view lies within its buffer and every accessor within its buffer
+/// views, so that reading them can't go out of bounds.
+fn validate_accessors(gltf: &gltf::Document) -> Result<(), GltfError> {
+    for view in gltf.views() {
+        let end = view.offset().checked_add(view.length());
+        if end.is_none_or(|end| end > view.buffer().length()) {
+            return Err(GltfError::BufferViewOutOfRange { view: view.index() });
+        }
+    }
+    for accessor in gltf.accessors() {
+        let mut in_range = elements_in_view(
+            accessor.view(),
+            accessor.offset(),
+            accessor.count(),
+            accessor.size(),
+        );
+        if let Some(sparse) = accessor.sparse() {
+            let indices = sparse.indices();
+            let values = sparse.values();
+            in_range &= elements_in_view(
+                Some(indices.view()),
+                indices.offset(),
+                sparse.count(),
+                indices.index_type().size(),
+            ) && elements_in_view(
+                Some(values.view()),
+                values.offset(),
+                sparse.count(),
+                accessor.size(),
+            );
+        }
+        if !in_range {
+            return Err(GltfError::AccessorOutOfRange {
+                accessor: accessor.index(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the accessors which the glTF crate reads as a fixed type have a compatible type,
+/// as it panics otherwise.
+fn validate_accessor_types(gltf: &gltf::Document) -> Result<(), GltfError> {
+    use gltf::accessor::{DataType, Dimensions};
+    use gltf::animation::Property;
+
+    const FLOAT: &[DataType] = &[DataType::F32];
+    const NORMALIZED: &[DataType] = &[
+        DataType::F32,
+        DataType::I8,
+        DataType::U8,
+        DataType::I16,
+        DataType::U16,
+    ];
+
+    fn check(
+        accessor: gltf::Accessor,
+        dimensions: Dimensions,
+        data_types: &[DataType],
+    ) -> Result<(), GltfError> {
+        if accessor.dimensions() == dimensions && data_types.contains(&accessor.data_type()) {
+            Ok(())
+        } else {
+            Err(GltfError::InvalidAccessorType {
+                accessor: accessor.index(),
+            })
+        }
+    }
+
+    for primitive in gltf.meshes().flat_map(|mesh| mesh.primitives()) {
+        if let Some(indices) = primitive.indices() {
+            check(
+                indices,
+                Dimensions::Scalar,
+                &[DataType::U8, DataType::U16, DataType::U32],
+            )?;
+        }
+    }
+    for skin in gltf.skins() {
+        if let Some(inverse_bind_matrices) = skin.inverse_bind_matrices() {
+            check(inverse_bind_matrices, Dimensions::Mat4, FLOAT)?;
+        }
+    }
+    for channel in gltf.animations().flat_map(|animation| animation.channels()) {
+        let sampler = channel.sampler();
+        check(sampler.input(), Dimensions::Scalar, FLOAT)?;
+        let (dimensions, data_types) = match channel.target().property() {
+            Property::Translation | Property::Scale => (Dimensions::Vec3, FLOAT),
+            Property::Rotation => (Dimensions::Vec4, NORMALIZED),
+            Property::MorphTargetWeights => (Dimensions::Scalar, NORMALIZED),
+        };
+        check(sampler.output(), dimensions, data_types)?;
+    }
+    Ok(())
+}
+
+/// Returns true if `count` elements of `element_size` bytes starting at `offset` lie within
+/// `view`. Accessors without a view are filled with zeros and always in range.
+fn elements_in_view(
+    view: Option<gltf::buffer::View>,
+    offset: usize,
+    count: usize,
+    element_size: usize,
+) -> bool {
+    if count == 0 {
+        return false;
+    }
+    let Some(view) = view else {
+        return true;
+    };
+    let stride = view.stride().unwrap_or(element_size);
+    stride >= element_size
+        && stride
+            .checked_mul(count - 1)
+            .and_then(|length| length.checked_add(offset))
+            .and_then(|length| length.checked_add(element_size))
+            .is_some_and(|end| end <= view.length())
+}
+
+/// Checks that no node is its own descendant, which would make loading its scenes recurse
+/// forever.
+fn validate_node_graph(gltf: &gltf::Document) -> Result<(), GltfError> {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum Visit {
+        NotVisited,
+        InProgress,
+        Done,
+    }
+
+    let mut visits = vec![Visit::NotVisited; gltf.nodes().len()];
+    for root in gltf.nodes() {
+        if visits[root.index()] != Visit::NotVisited {
+            continue;
+        }
+        visits[root.index()] = Visit::InProgress;
+        let mut stack = vec![(root.index(), root.children())];
+        while let Some((node, children)) = stack.last_mut() {
+            let node = *node;
+            match children.next() {
+                Some(child) => match visits[child.index()] {
+                    Visit::NotVisited => {
+                        visits[child.index()] = Visit::InProgress;
+                        stack.push((child.index(), child.children()));
+                    }
+                    Visit::InProgress => {
+                        return Err(GltfError::NodeCycle {
+                            node: child.index(),
+                        })
+                    }
+                    Visit::Done => {}
+                },
+                None => {
+                    visits[node] = Visit::Done;
+                    stack.pop();
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the data of a buffer view, or `None` if it is out of range of its buffer.
+fn buffer_view_data<'a>(
+    view: &gltf::buffer::View,
+    buffer_data: &'a [Cow<'_, [u8]>],
+) -> Option<&'a [u8]> {
+    let start = view.offset();
+    let end = start.checked_add(view.length())?;
+    buffer_data.get(view.buffer().index())?.get(start..end)
+}
+
+/// Reads the external resources referred to by a glTF file and keeps track of their asset paths.
+#[derive(Default)]
+struct ExternalFiles {
+    /// The contents of the archive which the glTF file was loaded from, if any.
+    #[cfg(feature = "archive")]
+    archive: Option<Archive>,
+    dependencies: Mutex<Vec<PathBuf>>,
+}
+
+impl ExternalFiles {
+    /// Reads a resource referred to by a URI which isn't a data URI, returning its contents and
+    /// its asset path if it was read from one. URIs are resolved by the loader's [`UriResolver`],
+    /// then within the archive the glTF file was loaded from and finally relative to the glTF
+    /// file.
+    ///
+    /// Reading asset paths through the [`LoadContext`] watches them for changes which reload the
+    /// glTF file. They aren't added as dependencies of the [`LoadedAsset`]s because the asset
+    /// server would then load each of them as a separate asset.
+    async fn read(
+        &self,
+        uri: &str,
+        role: UriRole,
+        load_context: &LoadContext<'_>,
+        loader: &GltfLoader,
+    ) -> Result<(Cow<'_, [u8]>, Option<PathBuf>), GltfError> {
+        let resolved = loader
+            .uri_resolver
+            .as_ref()
+            .and_then(|resolver| resolver.resolve(uri, load_context.path(), role));
+        #[cfg(feature = "archive")]
+        if resolved.is_none() {
+            if let Some(bytes) = self.archive.as_ref().and_then(|archive| archive.read(uri)) {
+                return Ok((Cow::Borrowed(bytes), None));
+            }
+        }
+        match resolved
+            .unwrap_or_else(|| ResolvedUri::Path(default_uri_path(uri, load_context.path())))
+        {
+            ResolvedUri::Path(path) => {
+                self.dependencies.lock().unwrap().push(path.clone());
+                let bytes = load_context.read_asset_bytes(&path).await?;
+                Ok((Cow::Owned(bytes), Some(path)))
+            }
+            ResolvedUri::Bytes(bytes) => Ok((Cow::Owned(bytes), None)),
+        }
+    }
+
+    /// Returns the asset paths of all the files read so far.
+    fn dependencies(&self) -> Vec<PathBuf> {
+        let mut dependencies = self.dependencies.lock().unwrap().clone();
+        dependencies.sort_unstable();
+        dependencies.dedup();
+        dependencies
+    }
+}
+
 fn resolve_node_hierarchy(
     nodes_intermediate: Vec<(String, GltfNode, Vec<usize>)>,
-    asset_path: &Path,
-) -> Vec<(String, GltfNode)> {
-    let mut has_errored = false;
+    warnings: &mut Warnings,
+) -> Result<Vec<(String, GltfNode)>, GltfError> {
     let mut empty_children = VecDeque::new();
     let mut parents = vec![None; nodes_intermediate.len()];
     let mut unprocessed_nodes = nodes_intermediate
@@ -1332,28 +3315,37 @@ fn resolve_node_hierarchy(
             for child in &children {
                 if let Some(parent) = parents.get_mut(*child) {
                     *parent = Some(i);
-                } else if !has_errored {
-                    has_errored = true;
-                    warn!("Unexpected child in GLTF Mesh {}", child);
+                } else {
+                    warnings.push(GltfWarning::MissingChildNode {
+                        node: i,
+                        child: *child,
+                    })?;
                 }
             }
             let children = children.into_iter().collect::<HashSet<_>>();
             if children.is_empty() {
                 empty_children.push_back(i);
             }
-            (i, (label, node, children))
+            Ok((i, (label, node, children)))
         })
-        .collect::<HashMap<_, _>>();
+        .collect::<Result<HashMap<_, _>, GltfError>>()?;
     let mut nodes = std::collections::HashMap::<usize, (String, GltfNode)>::new();
     while let Some(index) = empty_children.pop_front() {
-        let (label, node, children) = unprocessed_nodes.remove(&index).unwrap();
-        assert!(children.is_empty());
+        let (label, node, children) = unprocessed_nodes
+            .remove(&index)
+            .ok_or(GltfError::InvalidNodeHierarchy { node: index })?;
+        if !children.is_empty() {
+            return Err(GltfError::InvalidNodeHierarchy { node: index });
+        }
         nodes.insert(index, (label, node));
         if let Some(parent_index) = parents[index] {
-            let (_, parent_node, parent_children) =
-                unprocessed_nodes.get_mut(&parent_index).unwrap();
+            let (_, parent_node, parent_children) = unprocessed_nodes
+                .get_mut(&parent_index)
+                .ok_or(GltfError::InvalidNodeHierarchy { node: parent_index })?;
 
-            assert!(parent_children.remove(&index));
+            if !parent_children.remove(&index) {
+                return Err(GltfError::InvalidNodeHierarchy { node: parent_index });
+            }
             if let Some((_, child_node)) = nodes.get(&index) {
                 parent_node.children.push(child_node.clone());
             }
@@ -1363,14 +3355,16 @@ fn resolve_node_hierarchy(
         }
     }
     if !unprocessed_nodes.is_empty() {
-        warn!("GLTF model must be a tree: {:?}", asset_path);
+        let mut nodes = unprocessed_nodes.into_keys().collect::<Vec<_>>();
+        nodes.sort_unstable();
+        warnings.push(GltfWarning::NodeHierarchyNotTree { nodes })?;
     }
     let mut nodes_to_sort = nodes.into_iter().collect::<Vec<_>>();
     nodes_to_sort.sort_by_key(|(i, _)| *i);
-    nodes_to_sort
+    Ok(nodes_to_sort
         .into_iter()
         .map(|(_, resolved)| resolved)
-        .collect()
+        .collect())
 }
 
 struct DataUri<'a> {
@@ -1412,10 +3406,54 @@ impl<'a> DataUri<'a> {
 
 #[cfg(test)]
 mod test {
-    use std::path::PathBuf;
+    use std::{
+        any::Any,
+        borrow::Cow,
+        num::NonZeroU8,
+        panic::AssertUnwindSafe,
+        path::{Path, PathBuf},
+        sync::{
+            mpsc::{channel, Receiver, Sender},
+            Arc, Mutex,
+        },
+        time::Duration,
+    };
+
+    use anyhow::Result;
+    use bevy::app::App;
+    use bevy::asset::{
+        AddAsset, Asset, AssetIo, AssetIoError, AssetLoader, AssetPlugin, AssetServer, Assets,
+        BoxedFuture, FileType, Handle, LoadContext, LoadState, Metadata,
+    };
+    use bevy::core::TaskPoolPlugin;
+    use bevy::ecs::{entity::Entity, world::World};
+    use bevy::math::Mat4;
+    use bevy::pbr::StandardMaterial;
+    use bevy::render::{
+        mesh::{
+            skinning::{SkinnedMesh, SkinnedMeshInverseBindposes},
+            Mesh,
+        },
+        render_resource::{FilterMode, SamplerDescriptor, TextureFormat},
+        texture::{CompressedImageFormats, Image, ImageSampler},
+    };
+    use bevy::scene::Scene;
+    use bevy::utils::HashMap;
+    use futures_lite::FutureExt;
 
-    use super::resolve_node_hierarchy;
-    use crate::GltfNode;
+    use super::{
+        asset_info, failed_image_placeholders, load_gltf, parse_gltf, placeholder_image,
+        reduce_joint_influences, resolve_node_hierarchy, GltfError, GltfLimit, GltfLoader,
+        ImageKey, ImageUsage, Warnings,
+    };
+    #[cfg(feature = "serde")]
+    use super::{get_gltf_extras, insert_extras_components, run_extras_handlers};
+    use crate::skinning::MAX_JOINTS;
+    use crate::{
+        Gltf, GltfAssetInfo, GltfAssetKinds, GltfExtras, GltfMaterialExtras, GltfMesh,
+        GltfMeshExtras, GltfNode, GltfPrimitive, GltfSamplerPolicy, GltfSceneExtras, GltfTexture,
+        GltfWarning, TextureErrorPolicy, TextureRole, UriRemapTable,
+    };
 
     impl GltfNode {
         fn empty() -> Self {
@@ -1431,8 +3469,9 @@ mod test {
     fn node_hierarchy_single_node() {
         let result = resolve_node_hierarchy(
             vec![("l1".to_string(), GltfNode::empty(), vec![])],
-            PathBuf::new().as_path(),
-        );
+            &mut Warnings::default(),
+        )
+        .unwrap();
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].0, "l1");
@@ -1446,8 +3485,9 @@ mod test {
                 ("l1".to_string(), GltfNode::empty(), vec![]),
                 ("l2".to_string(), GltfNode::empty(), vec![]),
             ],
-            PathBuf::new().as_path(),
-        );
+            &mut Warnings::default(),
+        )
+        .unwrap();
 
         assert_eq!(result.len(), 2);
         assert_eq!(result[0].0, "l1");
@@ -1463,8 +3503,9 @@ mod test {
                 ("l1".to_string(), GltfNode::empty(), vec![1]),
                 ("l2".to_string(), GltfNode::empty(), vec![]),
             ],
-            PathBuf::new().as_path(),
-        );
+            &mut Warnings::default(),
+        )
+        .unwrap();
 
         assert_eq!(result.len(), 2);
         assert_eq!(result[0].0, "l1");
@@ -1485,8 +3526,9 @@ mod test {
                 ("l6".to_string(), GltfNode::empty(), vec![]),
                 ("l7".to_string(), GltfNode::empty(), vec![]),
             ],
-            PathBuf::new().as_path(),
-        );
+            &mut Warnings::default(),
+        )
+        .unwrap();
 
         assert_eq!(result.len(), 7);
         assert_eq!(result[0].0, "l1");
@@ -1507,29 +3549,1435 @@ mod test {
 
     #[test]
     fn node_hierarchy_cyclic() {
+        let mut warnings = Warnings::default();
         let result = resolve_node_hierarchy(
             vec![
                 ("l1".to_string(), GltfNode::empty(), vec![1]),
                 ("l2".to_string(), GltfNode::empty(), vec![0]),
             ],
-            PathBuf::new().as_path(),
-        );
+            &mut warnings,
+        )
+        .unwrap();
 
         assert_eq!(result.len(), 0);
+        assert!(matches!(
+            warnings.list.as_slice(),
+            [GltfWarning::NodeHierarchyNotTree { nodes }] if nodes == &[0, 1]
+        ));
     }
 
     #[test]
     fn node_hierarchy_missing_node() {
+        let mut warnings = Warnings::default();
         let result = resolve_node_hierarchy(
             vec![
                 ("l1".to_string(), GltfNode::empty(), vec![2]),
                 ("l2".to_string(), GltfNode::empty(), vec![]),
             ],
-            PathBuf::new().as_path(),
-        );
+            &mut warnings,
+        )
+        .unwrap();
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].0, "l2");
         assert_eq!(result[0].1.children.len(), 0);
+        assert!(matches!(
+            warnings.list.as_slice(),
+            [
+                GltfWarning::MissingChildNode { node: 0, child: 2 },
+                GltfWarning::NodeHierarchyNotTree { nodes },
+            ] if nodes == &[0]
+        ));
+    }
+
+    #[test]
+    fn node_hierarchy_strict() {
+        let result = resolve_node_hierarchy(
+            vec![
+                ("l1".to_string(), GltfNode::empty(), vec![1]),
+                ("l2".to_string(), GltfNode::empty(), vec![0]),
+            ],
+            &mut Warnings::new(true),
+        );
+
+        assert!(matches!(
+            result,
+            Err(GltfError::NodeHierarchyNotTree { .. })
+        ));
+    }
+
+    #[test]
+    fn strict_mode_fails_only_on_lost_data() {
+        let mut warnings = Warnings::new(true);
+        warnings
+            .push(GltfWarning::TextureColorSpaceConflict { image: 0 })
+            .unwrap();
+        warnings
+            .push(GltfWarning::MipmapsNotGenerated { image: 0 })
+            .unwrap();
+        assert_eq!(warnings.list.len(), 2);
+
+        let result = warnings.push(GltfWarning::SparseAnimationInput {
+            animation: 1,
+            channel: 2,
+        });
+        assert!(matches!(
+            result,
+            Err(GltfError::SparseAnimationInput {
+                animation: 1,
+                channel: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn sampler_policy_anisotropy_requires_linear_filtering() {
+        let policy = GltfSamplerPolicy {
+            anisotropy_clamp: NonZeroU8::new(16),
+            ..Default::default()
+        };
+
+        let mut nearest = SamplerDescriptor::default();
+        policy.apply(&mut nearest);
+        assert_eq!(nearest.anisotropy_clamp, None);
+
+        let mut linear = SamplerDescriptor {
+            mag_filter: FilterMode::Linear,
+            min_filter: FilterMode::Linear,
+            mipmap_filter: FilterMode::Linear,
+            ..Default::default()
+        };
+        policy.apply(&mut linear);
+        assert_eq!(linear.anisotropy_clamp, NonZeroU8::new(16));
+    }
+
+    #[test]
+    fn sampler_policy_forced_filter() {
+        let policy = GltfSamplerPolicy {
+            filter: Some(FilterMode::Nearest),
+            anisotropy_clamp: NonZeroU8::new(4),
+            ..Default::default()
+        };
+
+        let mut sampler = SamplerDescriptor {
+            mag_filter: FilterMode::Linear,
+            min_filter: FilterMode::Linear,
+            mipmap_filter: FilterMode::Linear,
+            ..Default::default()
+        };
+        policy.apply(&mut sampler);
+        assert_eq!(sampler.mag_filter, FilterMode::Nearest);
+        assert_eq!(sampler.mipmap_filter, FilterMode::Nearest);
+        assert_eq!(sampler.anisotropy_clamp, None);
+    }
+
+    #[test]
+    fn placeholder_images() {
+        let checker = placeholder_image(ImageUsage::Color);
+        assert_eq!(&checker.data[..4], [255, 0, 255, 255]);
+        assert_eq!(&checker.data[16..20], [0, 0, 0, 255]);
+
+        let normal = placeholder_image(ImageUsage::NormalMap);
+        assert!(normal.data.chunks(4).all(|t| t == [128, 128, 255, 255]));
+
+        let occlusion = placeholder_image(ImageUsage::Data);
+        assert!(occlusion.data.iter().all(|v| *v == 255));
+    }
+
+    #[test]
+    fn glb_blob_is_borrowed() {
+        let json = br#"{"asset":{"version":"2.0"},"buffers":[{"byteLength":4}]}    "#;
+        let bin = [1u8, 2, 3, 4];
+        let mut glb = Vec::new();
+        glb.extend_from_slice(b"glTF");
+        glb.extend_from_slice(&2u32.to_le_bytes());
+        glb.extend_from_slice(&(12 + 8 + json.len() as u32 + 8 + 4).to_le_bytes());
+        glb.extend_from_slice(&(json.len() as u32).to_le_bytes());
+        glb.extend_from_slice(b"JSON");
+        glb.extend_from_slice(json);
+        glb.extend_from_slice(&4u32.to_le_bytes());
+        glb.extend_from_slice(b"BIN\0");
+        glb.extend_from_slice(&bin);
+
+        let (document, blob) = parse_gltf(&glb).unwrap();
+        assert_eq!(document.buffers().len(), 1);
+        match blob {
+            Some(Cow::Borrowed(blob)) => {
+                assert_eq!(blob, bin);
+                assert!(glb.as_ptr_range().contains(&blob.as_ptr()));
+            }
+            _ => panic!("GLB binary chunk was not borrowed"),
+        }
+    }
+
+    /// Serves files from memory.
+    struct MemoryAssetIo(HashMap<PathBuf, Vec<u8>>);
+
+    impl AssetIo for MemoryAssetIo {
+        fn load_path<'a>(
+            &'a self,
+            path: &'a Path,
+        ) -> BoxedFuture<'a, Result<Vec<u8>, AssetIoError>> {
+            Box::pin(async move {
+                self.0
+                    .get(path)
+                    .cloned()
+                    .ok_or_else(|| AssetIoError::NotFound(path.to_path_buf()))
+            })
+        }
+
+        fn read_directory(
+            &self,
+            path: &Path,
+        ) -> Result<Box<dyn Iterator<Item = PathBuf>>, AssetIoError> {
+            Err(AssetIoError::NotFound(path.to_path_buf()))
+        }
+
+        fn get_metadata(&self, _path: &Path) -> Result<Metadata, AssetIoError> {
+            Ok(Metadata::new(FileType::File))
+        }
+
+        fn watch_path_for_changes(
+            &self,
+            _to_watch: &Path,
+            _to_reload: Option<PathBuf>,
+        ) -> Result<(), AssetIoError> {
+            Ok(())
+        }
+
+        fn watch_for_changes(&self) -> Result<(), AssetIoError> {
+            Ok(())
+        }
+    }
+
+    /// The result of loading a file, or the payload of the panic it caused.
+    type Outcome = std::thread::Result<Result<(), GltfError>>;
+
+    /// Loads glTF files like [`GltfLoader`], reporting whether each load succeeded, failed or
+    /// panicked.
+    struct ProbeLoader {
+        loader: GltfLoader,
+        outcomes: Mutex<Sender<(PathBuf, Outcome)>>,
+    }
+
+    impl AssetLoader for ProbeLoader {
+        fn load<'a>(
+            &'a self,
+            bytes: &'a [u8],
+            load_context: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<()>> {
+            Box::pin(async move {
+                let path = load_context.path().to_path_buf();
+                let outcome = AssertUnwindSafe(load_gltf(bytes, load_context, &self.loader))
+                    .catch_unwind()
+                    .await;
+                self.outcomes.lock().unwrap().send((path, outcome)).unwrap();
+                Ok(())
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["gltf", "glb"]
+        }
+    }
+
+    fn test_loader() -> GltfLoader {
+        GltfLoader {
+            supported_compressed_formats: CompressedImageFormats::NONE,
+            custom_vertex_attributes: HashMap::default(),
+            generate_mipmaps: false,
+            texture_size_limits: Default::default(),
+            sampler_policy: Default::default(),
+            sampler_override: None,
+            texture_error_policy: Default::default(),
+            uri_resolver: None,
+            asset_kinds: Default::default(),
+            strict: false,
+            limits: Default::default(),
+            joint_influences: Default::default(),
+            #[cfg(feature = "serde")]
+            extras_components_key: None,
+            #[cfg(feature = "serde")]
+            type_registry: Default::default(),
+            #[cfg(feature = "serde")]
+            extras_handlers: HashMap::default(),
+            scene_post_processors: Vec::new(),
+        }
+    }
+
+    /// Builds an app which loads `files` from memory with `loader`, sending the outcome of each
+    /// load to the returned receiver.
+    fn test_app(
+        loader: GltfLoader,
+        files: Vec<(String, Vec<u8>)>,
+    ) -> (App, Receiver<(PathBuf, Outcome)>) {
+        let (sender, receiver) = channel();
+        let mut app = App::new();
+        app.insert_resource(AssetServer::new(MemoryAssetIo(
+            files
+                .into_iter()
+                .map(|(path, bytes)| (PathBuf::from(path), bytes))
+                .collect(),
+        )))
+        .add_plugin(TaskPoolPlugin::default())
+        .add_plugin(AssetPlugin::default())
+        .add_asset::<Gltf>()
+        .add_asset::<GltfNode>()
+        .add_asset::<GltfMesh>()
+        .add_asset::<GltfPrimitive>()
+        .add_asset::<GltfTexture>()
+        .add_asset::<Mesh>()
+        .add_asset::<Image>()
+        .add_asset::<StandardMaterial>()
+        .add_asset::<Scene>()
+        .add_asset::<SkinnedMeshInverseBindposes>()
+        .add_asset_loader(ProbeLoader {
+            loader,
+            outcomes: Mutex::new(sender),
+        });
+        (app, receiver)
+    }
+
+    /// Loads each of the glTF `files` with `loader`, returning their outcomes by path. Other
+    /// files are only read by the glTF files.
+    fn load_files(loader: GltfLoader, files: Vec<(String, Vec<u8>)>) -> HashMap<PathBuf, Outcome> {
+        let paths = files
+            .iter()
+            .map(|(path, _)| path.clone())
+            .filter(|path| {
+                loader
+                    .extensions()
+                    .iter()
+                    .any(|extension| path.ends_with(extension))
+            })
+            .collect::<Vec<_>>();
+        let (app, receiver) = test_app(loader, files);
+
+        // Load one file at a time, as loads nest on the task pool threads while waiting for scopes
+        let asset_server = app.world.resource::<AssetServer>();
+        paths
+            .iter()
+            .map(|path| {
+                let _handle = asset_server.load_untyped(path.as_str());
+                receiver.recv_timeout(Duration::from_secs(60)).unwrap()
+            })
+            .collect()
+    }
+
+    /// Loads the file at `path` with `loader`, which must succeed, returning the app once all of
+    /// the file's assets have been stored.
+    fn load_app(loader: GltfLoader, path: &str, bytes: Vec<u8>) -> App {
+        load_app_with_files(loader, path, vec![(path.to_string(), bytes)])
+    }
+
+    /// Like [`load_app`], with the file at `path` and the external files it uses in `files`.
+    fn load_app_with_files(loader: GltfLoader, path: &str, files: Vec<(String, Vec<u8>)>) -> App {
+        let (mut app, receiver) = test_app(loader, files);
+        let handle = app.world.resource::<AssetServer>().load_untyped(path);
+        let (_, outcome) = receiver.recv_timeout(Duration::from_secs(60)).unwrap();
+        let result = outcome.unwrap();
+        assert!(result.is_ok(), "{result:?}");
+        for _ in 0..1000 {
+            app.update();
+            if app.world.resource::<AssetServer>().get_load_state(&handle) == LoadState::Loaded {
+                return app;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        panic!("{path} wasn't stored");
+    }
+
+    /// Returns the asset labelled `label` in the file at `path` loaded into `app`.
+    fn labeled_asset<'a, T: Asset>(app: &'a App, path: &str, label: &str) -> Option<&'a T> {
+        let handle = app
+            .world
+            .resource::<AssetServer>()
+            .get_handle::<T, _>(format!("{path}#{label}").as_str());
+        app.world.resource::<Assets<T>>().get(&handle)
+    }
+
+    fn panic_message(payload: &(dyn Any + Send)) -> &str {
+        payload
+            .downcast_ref::<String>()
+            .map(String::as_str)
+            .or_else(|| payload.downcast_ref::<&str>().copied())
+            .unwrap_or_default()
+    }
+
+    /// Builds a glTF file with a skinned, textured triangle from JSON with `$BUFFER` in place of
+    /// its buffer URI.
+    fn triangle_gltf(json: &str) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        for position in [[0.0f32, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]] {
+            for component in position {
+                buffer.extend_from_slice(&component.to_le_bytes());
+            }
+        }
+        for index in [0u16, 1, 2, 0] {
+            buffer.extend_from_slice(&index.to_le_bytes());
+        }
+        for (i, component) in [1.0f32; 16].iter().enumerate() {
+            let value = if i % 5 == 0 { *component } else { 0.0 };
+            buffer.extend_from_slice(&value.to_le_bytes());
+        }
+        let uri = format!(
+            "data:application/octet-stream;base64,{}",
+            base64::encode(&buffer)
+        );
+        json.replace("$BUFFER", &uri).into_bytes()
+    }
+
+    const TRIANGLE: &str = r#"{
+        "asset": {"version": "2.0"},
+        "buffers": [{"byteLength": 108, "uri": "$BUFFER"}],
+        "bufferViews": [
+            {"buffer": 0, "byteOffset": 0, "byteLength": 36},
+            {"buffer": 0, "byteOffset": 36, "byteLength": 6},
+            {"buffer": 0, "byteOffset": 44, "byteLength": 64}
+        ],
+        "accessors": [
+            {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+                "min": [0, 0, 0], "max": [1, 1, 0]},
+            {"bufferView": 1, "componentType": 5123, "count": 3, "type": "SCALAR"},
+            {"bufferView": 2, "componentType": 5126, "count": 1, "type": "MAT4"}
+        ],
+        "meshes": [{"primitives": [{"attributes": {"POSITION": 0}, "indices": 1, "material": 0}]}],
+        "materials": [{"pbrMetallicRoughness": {"baseColorTexture": {"index": 0}},
+            "normalTexture": {"index": 0}}],
+        "textures": [{"source": 0}],
+        "images": [{"uri": "missing.png"}],
+        "skins": [{"joints": [1], "inverseBindMatrices": 2}],
+        "nodes": [{"mesh": 0, "skin": 0, "children": [1]}, {"name": "joint"}],
+        "scenes": [{"nodes": [0]}],
+        "scene": 0
+    }"#;
+
+    /// Several primitives and images, which are loaded on the task pool, sharing the buffer of
+    /// [`TRIANGLE`].
+    const PRIMITIVES: &str = r#"{
+        "asset": {"version": "2.0"},
+        "buffers": [{"byteLength": 108, "uri": "$BUFFER"}],
+        "bufferViews": [
+            {"buffer": 0, "byteOffset": 0, "byteLength": 36},
+            {"buffer": 0, "byteOffset": 36, "byteLength": 6},
+            {"buffer": 0, "byteOffset": 44, "byteLength": 64}
+        ],
+        "accessors": [
+            {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+                "min": [0, 0, 0], "max": [1, 1, 0]},
+            {"bufferView": 1, "componentType": 5123, "count": 3, "type": "SCALAR"},
+            {"bufferView": 2, "componentType": 5126, "count": 3, "type": "VEC2"}
+        ],
+        "meshes": [
+            {"primitives": [
+                {"attributes": {"POSITION": 0, "TEXCOORD_0": 2}, "indices": 1, "material": 0},
+                {"attributes": {"POSITION": 0}, "indices": 1, "material": 1},
+                {"attributes": {"POSITION": 0, "TEXCOORD_0": 2}, "material": 1}
+            ]},
+            {"primitives": [{"attributes": {"POSITION": 0}, "indices": 1}]}
+        ],
+        "materials": [
+            {"pbrMetallicRoughness": {"baseColorTexture": {"index": 0}},
+                "normalTexture": {"index": 1}},
+            {"occlusionTexture": {"index": 1}, "emissiveTexture": {"index": 2}}
+        ],
+        "textures": [{"source": 0}, {"source": 1, "sampler": 0}, {"source": 1}],
+        "samplers": [{"magFilter": 9729, "wrapS": 33071}],
+        "images": [{"uri": "color.ppm"}, {"uri": "data.ppm"}],
+        "nodes": [{"mesh": 0, "children": [1]}, {"mesh": 1}],
+        "scenes": [{"nodes": [0]}],
+        "scene": 0
+    }"#;
+
+    /// Returns the images used by [`PRIMITIVES`], with a truncated image for corrupting it.
+    fn primitives_images() -> Vec<(String, Vec<u8>)> {
+        let mut color = b"P6 2 2 255 ".to_vec();
+        color.extend_from_slice(&[255, 0, 0].repeat(4));
+        let mut data = b"P6 4 4 255 ".to_vec();
+        data.extend_from_slice(&[128, 128, 255].repeat(16));
+        let truncated = data[..20].to_vec();
+        vec![
+            ("color.ppm".to_string(), color),
+            ("data.ppm".to_string(), data),
+            ("truncated.ppm".to_string(), truncated),
+        ]
+    }
+
+    /// Returns the JSON of the triangle with each vertex influenced by one of `vertex_joints` in
+    /// a skin of `joints` joints.
+    fn skinned_triangle_json(vertex_joints: [u16; 3], joints: usize) -> String {
+        let mut skin_buffer = Vec::new();
+        for joint in vertex_joints {
+            for index in [joint, 0, 0, 0] {
+                skin_buffer.extend_from_slice(&index.to_le_bytes());
+            }
+        }
+        for _ in 0..3 {
+            for weight in [1.0f32, 0.0, 0.0, 0.0] {
+                skin_buffer.extend_from_slice(&weight.to_le_bytes());
+            }
+        }
+        let joint_nodes = (1..=joints)
+            .map(|node| node.to_string())
+            .collect::<Vec<_>>();
+        TRIANGLE
+            .replace(
+                r#""buffers": [{"byteLength": 108, "uri": "$BUFFER"}]"#,
+                &format!(
+                    r#""buffers": [{{"byteLength": 108, "uri": "$BUFFER"}},
+                        {{"byteLength": 72, "uri": "data:application/octet-stream;base64,{}"}}]"#,
+                    base64::encode(&skin_buffer)
+                ),
+            )
+            .replace(
+                r#"{"buffer": 0, "byteOffset": 44, "byteLength": 64}"#,
+                r#"{"buffer": 0, "byteOffset": 44, "byteLength": 64},
+                    {"buffer": 1, "byteOffset": 0, "byteLength": 24},
+                    {"buffer": 1, "byteOffset": 24, "byteLength": 48}"#,
+            )
+            .replace(
+                r#"{"bufferView": 2, "componentType": 5126, "count": 1, "type": "MAT4"}"#,
+                r#"{"bufferView": 3, "componentType": 5123, "count": 3, "type": "VEC4"},
+                    {"bufferView": 4, "componentType": 5126, "count": 3, "type": "VEC4"}"#,
+            )
+            .replace(
+                r#""attributes": {"POSITION": 0}"#,
+                r#""attributes": {"POSITION": 0, "JOINTS_0": 2, "WEIGHTS_0": 3}"#,
+            )
+            .replace(
+                r#""skins": [{"joints": [1], "inverseBindMatrices": 2}]"#,
+                &format!(r#""skins": [{{"joints": [{}]}}]"#, joint_nodes.join(", ")),
+            )
+            .replace(
+                r#"{"mesh": 0, "skin": 0, "children": [1]}, {"name": "joint"}"#,
+                &format!(
+                    r#"{{"mesh": 0, "skin": 0, "children": [{}]}}{}"#,
+                    joint_nodes.join(", "),
+                    ", {}".repeat(joints)
+                ),
+            )
+    }
+
+    #[test]
+    fn malformed_files_fail_without_panicking() {
+        let cases = [
+            ("valid", vec![]),
+            (
+                "buffer_uri",
+                vec![(r#""uri": "$BUFFER""#, r#""uri": "%FF.bin""#)],
+            ),
+            (
+                "buffer_short",
+                vec![(r#""byteLength": 108"#, r#""byteLength": 200"#)],
+            ),
+            (
+                "view_range",
+                vec![(
+                    r#""byteOffset": 44, "byteLength": 64"#,
+                    r#""byteOffset": 44, "byteLength": 65"#,
+                )],
+            ),
+            (
+                "view_overflow",
+                vec![(
+                    r#""byteOffset": 44"#,
+                    r#""byteOffset": 18446744073709551615"#,
+                )],
+            ),
+            (
+                "accessor_empty",
+                vec![(
+                    r#""count": 3, "type": "SCALAR""#,
+                    r#""count": 0, "type": "SCALAR""#,
+                )],
+            ),
+            (
+                "accessor_range",
+                vec![(
+                    r#""count": 3, "type": "SCALAR""#,
+                    r#""count": 4, "type": "SCALAR""#,
+                )],
+            ),
+            (
+                "accessor_overflow",
+                vec![(
+                    r#""count": 1, "type": "MAT4""#,
+                    r#""count": 18446744073709551615, "type": "MAT4""#,
+                )],
+            ),
+            (
+                "stride",
+                vec![(
+                    r#""byteOffset": 0, "byteLength": 36"#,
+                    r#""byteOffset": 0, "byteLength": 36, "byteStride": 4"#,
+                )],
+            ),
+            (
+                "index_range",
+                vec![(
+                    r#"{"bufferView": 1, "componentType": 5123"#,
+                    r#"{"bufferView": 0, "byteOffset": 12, "componentType": 5123"#,
+                )],
+            ),
+            (
+                "vertex_count",
+                vec![
+                    (
+                        r#""attributes": {"POSITION": 0}"#,
+                        r#""attributes": {"POSITION": 0, "NORMAL": 3}"#,
+                    ),
+                    (
+                        r#""count": 1, "type": "MAT4"}"#,
+                        r#""count": 1, "type": "MAT4"}, {"bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3"}"#,
+                    ),
+                ],
+            ),
+            (
+                "valid_without_inverse_bind_matrices",
+                vec![(r#", "inverseBindMatrices": 2"#, "")],
+            ),
+            (
+                "inverse_bind_matrix_count",
+                vec![(r#""joints": [1]"#, r#""joints": [1, 0]"#)],
+            ),
+            ("joint_outside_scene", vec![(r#", "children": [1]"#, "")]),
+            (
+                "node_cycle",
+                vec![(
+                    r#"{"name": "joint"}"#,
+                    r#"{"name": "joint", "children": [0]}"#,
+                )],
+            ),
+            (
+                "node_index",
+                vec![(r#""children": [1]"#, r#""children": [5]"#)],
+            ),
+            (
+                "valid_tangent_format",
+                vec![(
+                    r#""attributes": {"POSITION": 0}"#,
+                    r#""attributes": {"POSITION": 0, "TANGENT": 0}"#,
+                )],
+            ),
+        ];
+        let primitives_cases = [
+            ("valid_primitives", vec![]),
+            (
+                "valid_primitives_truncated_image",
+                vec![(r#""uri": "data.ppm""#, r#""uri": "truncated.ppm""#)],
+            ),
+            (
+                "valid_primitives_missing_image",
+                vec![(r#""uri": "color.ppm""#, r#""uri": "missing.ppm""#)],
+            ),
+            (
+                "valid_primitives_tangent_format",
+                vec![(
+                    r#""attributes": {"POSITION": 0}, "indices": 1, "material": 1"#,
+                    r#""attributes": {"POSITION": 0, "TANGENT": 2}, "indices": 1, "material": 1"#,
+                )],
+            ),
+            (
+                "primitives_index_type",
+                vec![(
+                    r#"{"primitives": [{"attributes": {"POSITION": 0}, "indices": 1}]}"#,
+                    r#"{"primitives": [{"attributes": {"POSITION": 0}, "indices": 0}]}"#,
+                )],
+            ),
+            (
+                "primitives_vertex_count",
+                vec![(
+                    r#""count": 3, "type": "VEC2""#,
+                    r#""count": 2, "type": "VEC2""#,
+                )],
+            ),
+            (
+                "primitives_image_view",
+                vec![(
+                    r#"{"uri": "data.ppm"}"#,
+                    r#"{"bufferView": 5, "mimeType": "image/png"}"#,
+                )],
+            ),
+        ];
+        let mut files = primitives_images();
+        for (fixture, cases) in [(TRIANGLE, &cases[..]), (PRIMITIVES, &primitives_cases)] {
+            files.extend(cases.iter().map(|(name, replacements)| {
+                let json = replacements
+                    .iter()
+                    .fold(fixture.to_string(), |json, (from, to)| {
+                        assert!(json.contains(from), "{name}: {from}");
+                        json.replace(from, to)
+                    });
+                (format!("{name}.gltf"), triangle_gltf(&json))
+            }));
+        }
+        let outcomes = load_files(test_loader(), files);
+
+        for (name, _) in cases.iter().chain(&primitives_cases) {
+            let outcome = &outcomes[&PathBuf::from(format!("{name}.gltf"))];
+            match outcome {
+                Ok(result) => assert_eq!(
+                    result.is_ok(),
+                    name.starts_with("valid"),
+                    "{name}: {result:?}"
+                ),
+                Err(payload) => panic!("{name} panicked: {}", panic_message(&**payload)),
+            }
+        }
+    }
+
+    #[test]
+    fn mutated_files_never_panic() {
+        // Replace each number outside of strings with values likely to be out of range
+        let mut files = primitives_images();
+        for (fixture, json) in [("triangle", TRIANGLE), ("primitives", PRIMITIVES)] {
+            let mut numbers = vec![];
+            let mut in_string = false;
+            let mut start = None;
+            for (i, c) in json.char_indices() {
+                if c == '"' {
+                    in_string = !in_string;
+                }
+                match (start, !in_string && c.is_ascii_digit()) {
+                    (None, true) => start = Some(i),
+                    (Some(begin), false) => {
+                        numbers.push(begin..i);
+                        start = None;
+                    }
+                    _ => {}
+                }
+            }
+            for range in numbers {
+                for value in [
+                    "0",
+                    "1",
+                    "2",
+                    "4",
+                    "7",
+                    "64",
+                    "4294967295",
+                    "18446744073709551615",
+                ] {
+                    let mut mutated = json.to_string();
+                    mutated.replace_range(range.clone(), value);
+                    files.push((
+                        format!("{fixture}_number_{}_{value}.gltf", range.start),
+                        triangle_gltf(&mutated),
+                    ));
+                }
+            }
+        }
+
+        // Truncate and corrupt a GLB version of the file
+        let gltf = triangle_gltf(TRIANGLE);
+        let mut glb = Vec::new();
+        let bin = base64::decode(
+            std::str::from_utf8(&gltf)
+                .unwrap()
+                .split("base64,")
+                .nth(1)
+                .unwrap()
+                .split('"')
+                .next()
+                .unwrap(),
+        )
+        .unwrap();
+        let json = TRIANGLE.replace(r#", "uri": "$BUFFER""#, "");
+        let json_length = json.len().div_ceil(4) * 4;
+        glb.extend_from_slice(b"glTF");
+        glb.extend_from_slice(&2u32.to_le_bytes());
+        glb.extend_from_slice(&(12 + 8 + json_length as u32 + 8 + bin.len() as u32).to_le_bytes());
+        glb.extend_from_slice(&(json_length as u32).to_le_bytes());
+        glb.extend_from_slice(b"JSON");
+        glb.extend_from_slice(json.as_bytes());
+        glb.resize(20 + json_length, b' ');
+        glb.extend_from_slice(&(bin.len() as u32).to_le_bytes());
+        glb.extend_from_slice(b"BIN\0");
+        glb.extend_from_slice(&bin);
+        files.push(("complete.glb".to_string(), glb.clone()));
+        for length in (0..glb.len()).step_by(7) {
+            files.push((format!("truncated_{length}.glb"), glb[..length].to_vec()));
+        }
+        let mut state = 0x2545_f491_u32;
+        for i in 0..64 {
+            let mut corrupted = glb.clone();
+            for _ in 0..4 {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                let position = state as usize % corrupted.len();
+                corrupted[position] ^= (state >> 24) as u8 | 1;
+            }
+            files.push((format!("corrupted_{i}.glb"), corrupted));
+        }
+
+        let outcomes = load_files(test_loader(), files);
+        for (path, outcome) in &outcomes {
+            if let Err(payload) = outcome {
+                panic!("{} panicked: {}", path.display(), panic_message(&**payload));
+            }
+        }
+        if let Some(outcome) = outcomes.get(Path::new("complete.glb")) {
+            assert!(matches!(outcome, Ok(Ok(()))), "{outcome:?}");
+        }
+    }
+
+    #[test]
+    fn textures_share_images() {
+        let json = TRIANGLE
+            .replace(
+                r#""materials": [{"pbrMetallicRoughness": {"baseColorTexture": {"index": 0}},
+            "normalTexture": {"index": 0}}]"#,
+                r#""materials": [{"pbrMetallicRoughness": {"baseColorTexture": {"index": 0}},
+            "emissiveTexture": {"index": 1}, "normalTexture": {"index": 2},
+            "occlusionTexture": {"index": 2}}]"#,
+            )
+            .replace(
+                r#""textures": [{"source": 0}]"#,
+                r#""textures": [{"source": 0}, {"source": 0, "sampler": 0}, {"source": 0},
+                    {"source": 0}],
+                "samplers": [{"magFilter": 9729}]"#,
+            );
+        let mut loader = test_loader();
+        loader.texture_error_policy = TextureErrorPolicy::Placeholder;
+        loader.sampler_override = Some(Arc::new(|_, roles, sampler| {
+            if roles.contains(&TextureRole::Emissive) {
+                sampler.lod_min_clamp = 1.0;
+            }
+        }));
+        let app = load_app(loader, "shared.gltf", triangle_gltf(&json));
+
+        // Textures using the image in the same color space with the same sampler share a copy
+        let asset_server = app.world.resource::<AssetServer>();
+        let image = asset_server.get_handle::<Image, _>("shared.gltf#Image0");
+        let sampler_image = asset_server.get_handle::<Image, _>("shared.gltf#Image0/Sampler1");
+        let linear_image = asset_server.get_handle::<Image, _>("shared.gltf#Image0/Linear");
+        let normal_map_image = asset_server.get_handle::<Image, _>("shared.gltf#Image0/NormalMap");
+        let images = app.world.resource::<Assets<Image>>();
+        assert_eq!(images.len(), 4);
+        let textures = (0..4)
+            .map(|texture| {
+                labeled_asset::<GltfTexture>(&app, "shared.gltf", &format!("Texture{texture}"))
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(textures[0].image, image);
+        assert_eq!(textures[1].image, sampler_image);
+        assert_eq!(textures[2].image, linear_image);
+        assert_eq!(
+            textures[2].normal_map_image.as_ref(),
+            Some(&normal_map_image)
+        );
+        assert_eq!(textures[3].image, image);
+        assert!(textures
+            .iter()
+            .all(|texture| texture.linear_image.is_none()));
+
+        // Each texture's image is sampled with the texture's own sampler
+        assert_eq!(textures[0].sampler.mag_filter, FilterMode::Nearest);
+        assert_eq!(textures[1].sampler.mag_filter, FilterMode::Linear);
+        assert_eq!(textures[1].sampler.lod_min_clamp, 1.0);
+        for texture in &textures {
+            assert!(matches!(
+                &images.get(&texture.image).unwrap().sampler_descriptor,
+                ImageSampler::Descriptor(sampler) if *sampler == texture.sampler
+            ));
+        }
+
+        let material = labeled_asset::<StandardMaterial>(&app, "shared.gltf", "Material0").unwrap();
+        assert_eq!(material.base_color_texture.as_ref(), Some(&image));
+        assert_eq!(material.emissive_texture.as_ref(), Some(&sampler_image));
+        assert_eq!(material.occlusion_texture.as_ref(), Some(&linear_image));
+        assert_eq!(
+            material.normal_map_texture.as_ref(),
+            Some(&normal_map_image)
+        );
+
+        let gltf = asset_server.get_handle::<Gltf, _>("shared.gltf");
+        let gltf = app.world.resource::<Assets<Gltf>>().get(&gltf).unwrap();
+        assert!(gltf
+            .warnings
+            .iter()
+            .any(|warning| matches!(warning, GltfWarning::TextureColorSpaceConflict { image: 0 })));
+    }
+
+    #[test]
+    fn normal_maps_filtered_apart_from_other_data() {
+        // +X and +Z unit normals
+        let mut texture = b"P6 2 2 255 ".to_vec();
+        texture.extend_from_slice(&[[255, 128, 128], [128, 128, 255]].repeat(2).concat());
+        let json = TRIANGLE.replace("missing.png", "texture.ppm").replace(
+            r#"{"pbrMetallicRoughness": {"baseColorTexture": {"index": 0}},"#,
+            r#"{"occlusionTexture": {"index": 0},"#,
+        );
+        let mut loader = test_loader();
+        loader.generate_mipmaps = true;
+        let app = load_app_with_files(
+            loader,
+            "normals.gltf",
+            vec![
+                ("normals.gltf".to_string(), triangle_gltf(&json)),
+                ("texture.ppm".to_string(), texture),
+            ],
+        );
+
+        let mip = |label: &str| {
+            let image = labeled_asset::<Image>(&app, "normals.gltf", label).unwrap();
+            assert_eq!(image.texture_descriptor.mip_level_count, 2);
+            image.data[16..].to_vec()
+        };
+        // Only the normal map is renormalized
+        assert_eq!(mip("Image0"), [192, 128, 192, 255]);
+        assert_eq!(mip("Image0/NormalMap"), [218, 128, 218, 255]);
+    }
+
+    #[test]
+    fn failed_textures_skipped_in_materials() {
+        let app = load_app(test_loader(), "skipped.gltf", triangle_gltf(TRIANGLE));
+
+        let material =
+            labeled_asset::<StandardMaterial>(&app, "skipped.gltf", "Material0").unwrap();
+        assert_eq!(material.base_color_texture, None);
+        assert_eq!(material.normal_map_texture, None);
+        assert!(labeled_asset::<GltfTexture>(&app, "skipped.gltf", "Texture0").is_none());
+        assert!(app.world.resource::<Assets<Image>>().is_empty());
+    }
+
+    #[test]
+    fn image_format_found_for_extensionless_paths() {
+        let png = b"\x89PNG\r\n\x1a\n".to_vec();
+        let mut loader = test_loader();
+        loader.texture_error_policy = TextureErrorPolicy::Fail;
+        loader.uri_resolver = Some(Arc::new(
+            UriRemapTable::default()
+                .with("missing.png", "shared/0123abcd")
+                .with("0123abcd", "shared/0123abcd"),
+        ));
+        let outcomes = load_files(
+            loader,
+            vec![
+                ("uri_extension.gltf".to_string(), triangle_gltf(TRIANGLE)),
+                (
+                    "contents.gltf".to_string(),
+                    triangle_gltf(&TRIANGLE.replace("missing.png", "0123abcd")),
+                ),
+                ("shared/0123abcd".to_string(), png),
+            ],
+        );
+
+        // The format is found, so decoding gets as far as the truncated PNG data
+        for path in ["uri_extension.gltf", "contents.gltf"] {
+            let result = outcomes[Path::new(path)].as_ref().unwrap();
+            assert!(
+                matches!(result, Err(GltfError::ImageError(_))),
+                "{result:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn texture_size_limit_downscales_16_bit_images() {
+        let mut texture = b"P6 4 2 65535 ".to_vec();
+        texture.extend_from_slice(&[255; 4 * 2 * 3 * 2]);
+        let mut loader = test_loader();
+        loader.strict = true;
+        loader.texture_size_limits.max_dimension = Some(2);
+        let app = load_app_with_files(
+            loader,
+            "limited.gltf",
+            vec![
+                (
+                    "limited.gltf".to_string(),
+                    triangle_gltf(
+                        &TRIANGLE
+                            .replace("missing.png", "texture.ppm")
+                            .replace("normalTexture", "emissiveTexture"),
+                    ),
+                ),
+                ("texture.ppm".to_string(), texture),
+            ],
+        );
+
+        let image = labeled_asset::<Image>(&app, "limited.gltf", "Image0").unwrap();
+        assert_eq!(image.texture_descriptor.format, TextureFormat::Rgba16Uint);
+        assert_eq!(image.texture_descriptor.size.width, 2);
+        assert_eq!(image.texture_descriptor.size.height, 1);
+        assert_eq!(image.data, [255; 2 * 4 * 2]);
+    }
+
+    #[test]
+    fn strict_image_errors_not_replaced() {
+        let image_keys = [ImageKey {
+            image: 0,
+            usage: ImageUsage::Color,
+        }];
+        let mut loader = test_loader();
+        loader.strict = true;
+        loader.texture_error_policy = TextureErrorPolicy::Placeholder;
+        let warning = GltfError::ImageNotDownscaled {
+            image: 0,
+            max_dimension: 2,
+        };
+        let result = failed_image_placeholders(0, &image_keys, warning, &loader);
+        assert!(
+            matches!(result, Err(GltfError::ImageNotDownscaled { image: 0, .. })),
+            "{result:?}"
+        );
+
+        // Images which fail to load for other reasons are replaced
+        let (warning, placeholders) =
+            failed_image_placeholders(0, &image_keys, GltfError::MissingBlob, &loader).unwrap();
+        assert!(matches!(
+            warning,
+            GltfWarning::ImageLoadFailed { image: 0, .. }
+        ));
+        assert_eq!(placeholders.map(|placeholders| placeholders.len()), Some(1));
+    }
+
+    #[test]
+    fn limits_reject_files_exceeding_them() {
+        // The triangle has 108 buffer bytes, 3 vertices, 3 indices, 1 texture 4 pixels wide, 2
+        // nodes, 2 deep, and an animation channel with 4 keyframes
+        let json = TRIANGLE
+            .replace("missing.png", "texture.ppm")
+            .replace(
+                r#"{"bufferView": 2, "componentType": 5126, "count": 1, "type": "MAT4"}"#,
+                r#"{"bufferView": 2, "componentType": 5126, "count": 1, "type": "MAT4"},
+                    {"bufferView": 2, "componentType": 5126, "count": 4, "type": "SCALAR",
+                        "min": [0], "max": [1]},
+                    {"bufferView": 2, "byteOffset": 16, "componentType": 5126, "count": 4,
+                        "type": "VEC3"}"#,
+            )
+            .replace(
+                r#""scene": 0"#,
+                r#""scene": 0,
+                "animations": [{"channels": [{"sampler": 0, "target": {"node": 1, "path": "translation"}}],
+                    "samplers": [{"input": 3, "output": 4}]}]"#,
+            );
+        let mut texture = b"P6 4 2 255 ".to_vec();
+        texture.extend_from_slice(&[255; 4 * 2 * 3]);
+        let usage = [
+            (GltfLimit::BufferBytes, 108),
+            (GltfLimit::Vertices, 3),
+            (GltfLimit::Indices, 3),
+            (GltfLimit::Textures, 1),
+            (GltfLimit::TextureDimension, 4),
+            (GltfLimit::Nodes, 2),
+            (GltfLimit::NodeDepth, 2),
+            (GltfLimit::AnimationKeyframes, 4),
+        ];
+
+        for (limit, usage) in usage {
+            for max in [usage, usage - 1] {
+                let mut loader = test_loader();
+                let limits = &mut loader.limits;
+                match limit {
+                    GltfLimit::BufferBytes => limits.max_buffer_bytes = Some(max),
+                    GltfLimit::Vertices => limits.max_vertices = Some(max),
+                    GltfLimit::Indices => limits.max_indices = Some(max),
+                    GltfLimit::Textures => limits.max_textures = Some(max),
+                    GltfLimit::TextureDimension => limits.max_texture_dimension = Some(max as u32),
+                    GltfLimit::Nodes => limits.max_nodes = Some(max),
+                    GltfLimit::NodeDepth => limits.max_node_depth = Some(max),
+                    GltfLimit::AnimationKeyframes => limits.max_animation_keyframes = Some(max),
+                    #[cfg(feature = "archive")]
+                    GltfLimit::ArchiveBytes => unreachable!(),
+                }
+                // Textures which fail to decode for other reasons fail the load
+                loader.texture_error_policy = TextureErrorPolicy::Fail;
+                let outcomes = load_files(
+                    loader,
+                    vec![
+                        ("triangle.gltf".to_string(), triangle_gltf(&json)),
+                        ("texture.ppm".to_string(), texture.clone()),
+                    ],
+                );
+                let result = outcomes[Path::new("triangle.gltf")].as_ref().unwrap();
+                if max == usage {
+                    assert!(result.is_ok(), "{limit}: {result:?}");
+                } else {
+                    assert!(
+                        matches!(
+                            result,
+                            Err(GltfError::LimitExceeded { limit: exceeded, max: reported })
+                                if *exceeded == limit && *reported == max
+                        ),
+                        "{limit}: {result:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn joint_influences_reduced_and_normalized() {
+        let sets = [
+            (
+                vec![[1, 2, 3, 4], [1, 0, 0, 0]],
+                vec![[0.1, 0.4, 0.2, 0.0], [2.0, 0.0, 0.0, 0.0]],
+            ),
+            (
+                vec![[5, 6, 7, 8], [0, 0, 0, 0]],
+                vec![[0.3, 0.05, f32::NAN, -1.0], [0.0; 4]],
+            ),
+        ];
+
+        let four = reduce_joint_influences(&sets, 4);
+        assert_eq!(four.len(), 1);
+        assert_eq!(four[0].0, vec![[2, 5, 3, 1], [1, 0, 0, 0]]);
+        assert_eq!(four[0].1, vec![[0.4, 0.3, 0.2, 0.1], [1.0, 0.0, 0.0, 0.0]]);
+
+        let eight = reduce_joint_influences(&sets, 8);
+        assert_eq!(eight.len(), 2);
+        assert_eq!(eight[0].0[0], [2, 5, 3, 1]);
+        assert_eq!(eight[1].0[0], [6, 0, 0, 0]);
+        let total = eight[0].1[0].iter().chain(&eight[1].1[0]).sum::<f32>();
+        assert!((total - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn extra_inverse_bind_matrices_left_out() {
+        let mut matrices = Vec::new();
+        for scale in [1.0f32, 2.0] {
+            for i in 0..16 {
+                let value = if i % 5 == 0 { scale } else { 0.0 };
+                matrices.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        let json = TRIANGLE
+            .replace(
+                r#""buffers": [{"byteLength": 108, "uri": "$BUFFER"}]"#,
+                &format!(
+                    r#""buffers": [{{"byteLength": 108, "uri": "$BUFFER"}},
+                        {{"byteLength": 128, "uri": "data:application/octet-stream;base64,{}"}}]"#,
+                    base64::encode(&matrices)
+                ),
+            )
+            .replace(
+                r#"{"buffer": 0, "byteOffset": 44, "byteLength": 64}"#,
+                r#"{"buffer": 1, "byteOffset": 0, "byteLength": 128}"#,
+            )
+            .replace(
+                r#"{"bufferView": 2, "componentType": 5126, "count": 1, "type": "MAT4"}"#,
+                r#"{"bufferView": 2, "componentType": 5126, "count": 2, "type": "MAT4"}"#,
+            );
+        let app = load_app(test_loader(), "skinned.gltf", triangle_gltf(&json));
+        let bindposes =
+            labeled_asset::<SkinnedMeshInverseBindposes>(&app, "skinned.gltf", "Skin0").unwrap();
+        assert_eq!(&bindposes[..], &[Mat4::IDENTITY]);
+    }
+
+    #[test]
+    fn skins_exceeding_joint_limit_load() {
+        let json = skinned_triangle_json([0, 150, 299], 300);
+        let mut app = load_app(test_loader(), "skinned.gltf", triangle_gltf(&json));
+
+        // The triangle's three joints fit in a single part
+        assert!(
+            labeled_asset::<Mesh>(&app, "skinned.gltf", "Mesh0/Primitive0/Partition0").is_some()
+        );
+        assert!(
+            labeled_asset::<Mesh>(&app, "skinned.gltf", "Mesh0/Primitive0/Partition1").is_none()
+        );
+        let bindposes = labeled_asset::<SkinnedMeshInverseBindposes>(
+            &app,
+            "skinned.gltf",
+            "Skin0/Mesh0/Primitive0/Partition0",
+        )
+        .unwrap();
+        assert_eq!(bindposes.len(), 3);
+
+        let asset_server = app.world.resource::<AssetServer>();
+        let partition =
+            asset_server.get_handle::<Mesh, _>("skinned.gltf#Mesh0/Primitive0/Partition0");
+        let handle = asset_server.get_handle::<Scene, _>("skinned.gltf#Scene0");
+        let mut scenes = app.world.resource_mut::<Assets<Scene>>();
+        let scene = scenes.get_mut(&handle).unwrap();
+        let mut query = scene.world.query::<(&SkinnedMesh, &Handle<Mesh>)>();
+        let parts = query.iter(&scene.world).collect::<Vec<_>>();
+        assert_eq!(parts.len(), 1);
+        for (skinned_mesh, mesh) in parts {
+            assert!(skinned_mesh.joints.len() <= MAX_JOINTS);
+            assert_eq!(skinned_mesh.joints.len(), 3);
+            assert_eq!(mesh, &partition);
+        }
+    }
+
+    #[test]
+    fn joint_attributes_left_out_without_skins() {
+        let json = skinned_triangle_json([0, 0, 0], 1);
+        let mut loader = test_loader();
+        loader.asset_kinds.skins = false;
+        let app = load_app(loader, "unskinned.gltf", triangle_gltf(&json));
+
+        let mesh = labeled_asset::<Mesh>(&app, "unskinned.gltf", "Mesh0/Primitive0").unwrap();
+        assert!(mesh.attribute(Mesh::ATTRIBUTE_POSITION).is_some());
+        assert!(mesh.attribute(Mesh::ATTRIBUTE_JOINT_INDEX).is_none());
+        assert!(mesh.attribute(Mesh::ATTRIBUTE_JOINT_WEIGHT).is_none());
+    }
+
+    #[test]
+    fn tangents_generated_only_with_materials() {
+        // The inverse bind matrix doubles as +X normals and as texture coordinates, as MikkTSpace
+        // needs both, and Bevy's implementation needs indexed vertices
+        let json = TRIANGLE
+            .replace(
+                r#"{"buffer": 0, "byteOffset": 44, "byteLength": 64}"#,
+                r#"{"buffer": 0, "byteOffset": 44, "byteLength": 64},
+                    {"buffer": 0, "byteOffset": 44, "byteLength": 64, "byteStride": 20}"#,
+            )
+            .replace(
+                r#"{"bufferView": 2, "componentType": 5126, "count": 1, "type": "MAT4"}"#,
+                r#"{"bufferView": 2, "componentType": 5126, "count": 1, "type": "MAT4"},
+                    {"bufferView": 2, "componentType": 5126, "count": 3, "type": "VEC2"},
+                    {"bufferView": 3, "componentType": 5126, "count": 3, "type": "VEC3"}"#,
+            )
+            .replace(
+                r#""POSITION": 0}"#,
+                r#""POSITION": 0, "NORMAL": 4, "TEXCOORD_0": 3}"#,
+            );
+        for (asset_kinds, has_tangents) in [
+            (GltfAssetKinds::ALL, true),
+            (GltfAssetKinds::GEOMETRY, false),
+        ] {
+            let mut loader = test_loader();
+            loader.asset_kinds = asset_kinds;
+            let app = load_app(loader, "tangents.gltf", triangle_gltf(&json));
+
+            let mesh = labeled_asset::<Mesh>(&app, "tangents.gltf", "Mesh0/Primitive0").unwrap();
+            assert!(mesh.attribute(Mesh::ATTRIBUTE_NORMAL).is_some());
+            assert_eq!(
+                mesh.attribute(Mesh::ATTRIBUTE_TANGENT).is_some(),
+                has_tangents
+            );
+        }
+    }
+
+    #[test]
+    fn scene_post_processors_run() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut loader = test_loader();
+        let recorded = seen.clone();
+        loader.scene_post_processors = vec![
+            Arc::new(
+                |world: &mut World,
+                 _: &gltf::Document,
+                 _: &gltf::Scene,
+                 node_entities: &HashMap<usize, Entity>| {
+                    world.entity_mut(node_entities[&1]).despawn();
+                },
+            ),
+            Arc::new(
+                move |world: &mut World,
+                      gltf: &gltf::Document,
+                      scene: &gltf::Scene,
+                      node_entities: &HashMap<usize, Entity>| {
+                    let mut nodes = node_entities
+                        .iter()
+                        .map(|(&node, &entity)| (node, world.get_entity(entity).is_some()))
+                        .collect::<Vec<_>>();
+                    nodes.sort_unstable();
+                    recorded
+                        .lock()
+                        .unwrap()
+                        .push((gltf.scenes().len(), scene.index(), nodes));
+                },
+            ),
+        ];
+
+        let outcomes = load_files(
+            loader,
+            vec![("triangle.gltf".to_string(), triangle_gltf(TRIANGLE))],
+        );
+        let result = outcomes[Path::new("triangle.gltf")].as_ref().unwrap();
+        assert!(result.is_ok(), "{result:?}");
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![(1, 0, vec![(0, true), (1, false)])]
+        );
+    }
+
+    #[test]
+    fn asset_info_read_and_reflected() {
+        use bevy::app::AppTypeRegistry;
+
+        let json = TRIANGLE.replace(
+            r#""asset": {"version": "2.0"}"#,
+            r#""asset": {"version": "2.0", "generator": "exporter", "copyright": "CC0",
+                "extras": {"license": "CC0-1.0"}},
+                "extensionsUsed": ["EXT_unknown"], "extras": {"root": true}"#,
+        );
+        let (gltf, _) = parse_gltf(json.as_bytes()).unwrap();
+        let info = asset_info(&gltf);
+
+        assert_eq!(info.version, "2.0");
+        assert_eq!(info.min_version, None);
+        assert_eq!(info.generator.as_deref(), Some("exporter"));
+        assert_eq!(info.copyright.as_deref(), Some("CC0"));
+        assert_eq!(info.extensions_used, ["EXT_unknown"]);
+        assert!(info.extensions_required.is_empty());
+        assert_eq!(
+            info.asset_extras.as_ref().map(GltfExtras::value),
+            Some(r#"{"license": "CC0-1.0"}"#)
+        );
+        assert_eq!(
+            info.extras.as_ref().map(GltfExtras::value),
+            Some(r#"{"root": true}"#)
+        );
+
+        // Scenes are copied into worlds through reflection
+        let type_registry = AppTypeRegistry::default();
+        type_registry.write().register::<GltfAssetInfo>();
+        let mut world = World::new();
+        world.spawn(info);
+        let mut scene = Scene::new(world).clone_with(&type_registry).unwrap();
+        let mut query = scene.world.query::<&GltfAssetInfo>();
+        let info = query.single(&scene.world);
+        assert_eq!(info.generator.as_deref(), Some("exporter"));
+        assert_eq!(
+            info.extras.as_ref().map(GltfExtras::value),
+            Some(r#"{"root": true}"#)
+        );
+    }
+
+    #[test]
+    fn extras_components_on_scene_entities() {
+        let json = TRIANGLE
+            .replace(
+                r#""material": 0}]}]"#,
+                r#""material": 0, "extras": "primitive"}], "extras": "mesh"}]"#,
+            )
+            .replace(
+                r#""normalTexture": {"index": 0}}"#,
+                r#""normalTexture": {"index": 0}, "extras": "material"}"#,
+            )
+            .replace(r#"{"nodes": [0]}"#, r#"{"nodes": [0], "extras": "scene"}"#);
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let recorded = seen.clone();
+        let mut loader = test_loader();
+        loader.scene_post_processors = vec![Arc::new(
+            move |world: &mut World,
+                  _: &gltf::Document,
+                  _: &gltf::Scene,
+                  _: &HashMap<usize, Entity>| {
+                let mut recorded = recorded.lock().unwrap();
+                for extras in world.query::<&GltfSceneExtras>().iter(world) {
+                    recorded.push(extras.value().to_string());
+                }
+                let mut query =
+                    world.query::<(&GltfExtras, &GltfMeshExtras, &GltfMaterialExtras)>();
+                for (primitive, mesh, material) in query.iter(world) {
+                    recorded.extend(
+                        [primitive, &mesh.0, &material.0].map(|extras| extras.value().to_string()),
+                    );
+                }
+            },
+        )];
+
+        let outcomes = load_files(
+            loader,
+            vec![("extras.gltf".to_string(), triangle_gltf(&json))],
+        );
+        let result = outcomes[Path::new("extras.gltf")].as_ref().unwrap();
+        assert!(result.is_ok(), "{result:?}");
+        assert_eq!(
+            *seen.lock().unwrap(),
+            [r#""scene""#, r#""primitive""#, r#""mesh""#, r#""material""#]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn extras_components_inserted() {
+        use bevy::app::AppTypeRegistry;
+        use bevy::ecs::{component::Component, reflect::ReflectComponent, world::World};
+        use bevy::reflect::Reflect;
+
+        #[derive(Component, Reflect, Default, Debug, PartialEq)]
+        #[reflect(Component)]
+        struct Health {
+            max: u32,
+        }
+
+        let type_registry = AppTypeRegistry::default();
+        type_registry.write().register::<Health>();
+        let json = TRIANGLE.replace(
+            r#"{"name": "joint"}"#,
+            r#"{"name": "joint", "extras": {"bevy_components":
+                {"Health": {"max": 10}, "Unknown": {}, "Health2": 3}}},
+                {"extras": {"bevy_components": []}}"#,
+        );
+        let (gltf, _) = parse_gltf(json.as_bytes()).unwrap();
+        let mut world = World::new();
+        let entities = gltf
+            .nodes()
+            .map(|node| {
+                let mut entity = world.spawn_empty();
+                if let Some(extras) = get_gltf_extras(node.extras()) {
+                    entity.insert(extras);
+                }
+                (node.index(), entity.id())
+            })
+            .collect::<HashMap<_, _>>();
+        let mut warnings = Warnings::default();
+        insert_extras_components(
+            &mut world,
+            &entities,
+            "bevy_components",
+            &type_registry,
+            &mut warnings,
+        )
+        .unwrap();
+
+        assert_eq!(world.get::<Health>(entities[&1]), Some(&Health { max: 10 }));
+        assert_eq!(world.get::<Health>(entities[&0]), None);
+        assert!(
+            matches!(
+                &warnings.list[..],
+                [
+                    GltfWarning::ExtrasComponentFailed { node: 1, component: health2, .. },
+                    GltfWarning::ExtrasComponentFailed { node: 1, component: unknown, .. },
+                    GltfWarning::InvalidExtrasComponents { node: 2, .. },
+                ] if health2 == "Health2" && unknown == "Unknown"
+            ),
+            "{:?}",
+            warnings.list
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn extras_handlers_called() {
+        use bevy::ecs::{component::Component, world::World};
+        use std::sync::Arc;
+
+        use crate::ExtrasHandler;
+
+        #[derive(Component, Debug, PartialEq)]
+        struct SpawnPoint(String);
+
+        let mut handlers = HashMap::<String, Arc<ExtrasHandler>>::default();
+        handlers.insert(
+            "spawn_point".to_string(),
+            Arc::new(|value, entity| {
+                entity.insert(SpawnPoint(value.as_str().unwrap_or_default().to_string()));
+            }),
+        );
+        let mut world = World::new();
+        let marked = world
+            .spawn(GltfExtras::new(r#"{"spawn_point": "start"}"#.to_string()))
+            .id();
+        let unmarked = world
+            .spawn(GltfExtras::new(r#"{"collider": true}"#.to_string()))
+            .id();
+        let invalid = world.spawn(GltfExtras::new("[".to_string())).id();
+        run_extras_handlers(&mut world, &handlers);
+
+        assert_eq!(
+            world.get::<SpawnPoint>(marked),
+            Some(&SpawnPoint("start".to_string()))
+        );
+        assert_eq!(world.get::<SpawnPoint>(unmarked), None);
+        assert_eq!(world.get::<SpawnPoint>(invalid), None);
     }
 }
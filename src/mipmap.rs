@@ -0,0 +1,392 @@
+use bevy::math::Vec3;
+use bevy::render::{
+    render_resource::{TextureDimension, TextureFormat},
+    texture::Image,
+};
+
+/// How the channels of a texel are combined when downsampling.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(crate) enum TexelFilter {
+    /// Averages all channels as linear values.
+    Linear,
+    /// Averages the color channels in linear space and alpha as is.
+    Srgb,
+    /// Averages the color channels as unit vectors and renormalizes them.
+    NormalMap,
+}
+
+impl TexelFilter {
+    /// Returns the filter appropriate for the format of `image` together with the layout of its
+    /// texels, or `None` if it can't be filtered.
+    pub(crate) fn for_image(
+        image: &Image,
+        is_normal_map: bool,
+    ) -> Option<(TexelFilter, TexelLayout)> {
+        let format = image.texture_descriptor.format;
+        let layout = TexelLayout::for_format(format)?;
+        let filter = if format.describe().srgb {
+            TexelFilter::Srgb
+        } else if is_normal_map && layout.channels >= 3 {
+            TexelFilter::NormalMap
+        } else {
+            TexelFilter::Linear
+        };
+        Some((filter, layout))
+    }
+
+    /// Averages a 2x2 block of texels, appending the result to `output`.
+    fn average(self, layout: TexelLayout, block: [&[u8]; 4], output: &mut Vec<u8>) {
+        let mut sum = [0.0f32; 4];
+        for texel in block {
+            for (channel, sum) in sum.iter_mut().enumerate().take(layout.channels) {
+                let value = layout
+                    .channel
+                    .read(&texel[channel * layout.channel.size()..]);
+                *sum += match self {
+                    TexelFilter::Srgb if channel < 3 => srgb_to_linear(value),
+                    TexelFilter::NormalMap if channel < 3 => value * 2.0 - 1.0,
+                    _ => value,
+                };
+            }
+        }
+        let mut average = sum.map(|value| value / 4.0);
+
+        if self == TexelFilter::NormalMap {
+            let normal = Vec3::from_slice(&average[..3])
+                .try_normalize()
+                .unwrap_or(Vec3::Z);
+            average[..3].copy_from_slice(&(normal * 0.5 + 0.5).to_array());
+        } else if self == TexelFilter::Srgb {
+            for value in &mut average[..3] {
+                *value = linear_to_srgb(*value);
+            }
+        }
+        for value in &average[..layout.channels] {
+            layout.channel.write(*value, output);
+        }
+    }
+}
+
+/// The type of the channels of an uncompressed texture format.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum Channel {
+    /// 8-bit normalized integers.
+    Unorm8,
+    /// 16-bit integers, filtered as normalized values.
+    Unorm16,
+    /// 32-bit floats.
+    Float32,
+}
+
+impl Channel {
+    fn size(self) -> usize {
+        match self {
+            Channel::Unorm8 => 1,
+            Channel::Unorm16 => 2,
+            Channel::Float32 => 4,
+        }
+    }
+
+    /// Reads a channel from the start of `bytes`, scaling integers to between 0 and 1.
+    fn read(self, bytes: &[u8]) -> f32 {
+        match self {
+            Channel::Unorm8 => bytes[0] as f32 / 255.0,
+            Channel::Unorm16 => u16::from_ne_bytes([bytes[0], bytes[1]]) as f32 / 65535.0,
+            Channel::Float32 => f32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+        }
+    }
+
+    fn write(self, value: f32, output: &mut Vec<u8>) {
+        match self {
+            Channel::Unorm8 => output.push((value.clamp(0.0, 1.0) * 255.0).round() as u8),
+            Channel::Unorm16 => output.extend_from_slice(
+                &((value.clamp(0.0, 1.0) * 65535.0).round() as u16).to_ne_bytes(),
+            ),
+            Channel::Float32 => output.extend_from_slice(&value.to_ne_bytes()),
+        }
+    }
+}
+
+/// The layout of the texels of an uncompressed texture format which can be filtered.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(crate) struct TexelLayout {
+    channels: usize,
+    channel: Channel,
+}
+
+impl TexelLayout {
+    /// Returns the layout of `format`, or `None` if it's compressed or otherwise can't be
+    /// filtered.
+    pub(crate) fn for_format(format: TextureFormat) -> Option<TexelLayout> {
+        let (channels, channel) = match format {
+            TextureFormat::R8Unorm => (1, Channel::Unorm8),
+            TextureFormat::Rg8Unorm => (2, Channel::Unorm8),
+            TextureFormat::Rgba8Unorm
+            | TextureFormat::Rgba8UnormSrgb
+            | TextureFormat::Bgra8Unorm
+            | TextureFormat::Bgra8UnormSrgb => (4, Channel::Unorm8),
+            TextureFormat::R16Uint | TextureFormat::R16Unorm => (1, Channel::Unorm16),
+            TextureFormat::Rg16Uint | TextureFormat::Rg16Unorm => (2, Channel::Unorm16),
+            TextureFormat::Rgba16Uint | TextureFormat::Rgba16Unorm => (4, Channel::Unorm16),
+            TextureFormat::R32Float => (1, Channel::Float32),
+            TextureFormat::Rg32Float => (2, Channel::Float32),
+            TextureFormat::Rgba32Float => (4, Channel::Float32),
+            _ => return None,
+        };
+        Some(TexelLayout { channels, channel })
+    }
+
+    fn texel_size(self) -> usize {
+        self.channels * self.channel.size()
+    }
+}
+
+/// Generates a full mip chain for an uncompressed 2D image which has a single mip level. Returns
+/// false if the image has a single mip level but its format can't be filtered, leaving it
+/// unchanged. Other images are also left unchanged.
+pub(crate) fn generate_mipmaps(image: &mut Image, is_normal_map: bool) -> bool {
+    let descriptor = &image.texture_descriptor;
+    if descriptor.mip_level_count != 1 || descriptor.size.depth_or_array_layers != 1 {
+        return true;
+    }
+    let Some((filter, layout)) = TexelFilter::for_image(image, is_normal_map) else {
+        return false;
+    };
+
+    let (mut width, mut height) = (descriptor.size.width, descriptor.size.height);
+    let mut level = image.data.clone();
+    let mut mip_level_count = 1;
+    while width > 1 || height > 1 {
+        (level, width, height) = downsample(&level, width, height, filter, layout);
+        image.data.extend_from_slice(&level);
+        mip_level_count += 1;
+    }
+    image.texture_descriptor.mip_level_count = mip_level_count;
+    true
+}
+
+/// Halves the dimensions of an image until neither exceeds `max_dimension`. The largest levels
+/// are dropped from images with a mip chain, while other images must be uncompressed 2D images
+/// in a format which can be filtered to be downsampled. Returns false if the image is still too
+/// large.
+pub(crate) fn limit_size(image: &mut Image, max_dimension: u32, is_normal_map: bool) -> bool {
+    let descriptor = &image.texture_descriptor;
+    let (mut width, mut height) = (descriptor.size.width, descriptor.size.height);
+    let max_dimension = max_dimension.max(1);
+    let fits = |width: u32, height: u32| width <= max_dimension && height <= max_dimension;
+    if fits(width, height) {
+        return true;
+    }
+    if descriptor.dimension != TextureDimension::D2 || descriptor.size.depth_or_array_layers != 1 {
+        return false;
+    }
+
+    if descriptor.mip_level_count > 1 {
+        let info = descriptor.format.describe();
+        let (block_width, block_height) = info.block_dimensions;
+        let mut mip_level_count = descriptor.mip_level_count;
+        let mut dropped_bytes = 0;
+        while !fits(width, height) && mip_level_count > 1 {
+            let blocks = width.div_ceil(block_width as u32) * height.div_ceil(block_height as u32);
+            dropped_bytes += blocks as usize * info.block_size as usize;
+            (width, height) = ((width / 2).max(1), (height / 2).max(1));
+            mip_level_count -= 1;
+        }
+        image.data.drain(..dropped_bytes.min(image.data.len()));
+        image.texture_descriptor.mip_level_count = mip_level_count;
+    } else {
+        let Some((filter, layout)) = TexelFilter::for_image(image, is_normal_map) else {
+            return false;
+        };
+        while !fits(width, height) {
+            (image.data, width, height) = downsample(&image.data, width, height, filter, layout);
+        }
+    }
+    image.texture_descriptor.size.width = width;
+    image.texture_descriptor.size.height = height;
+    fits(width, height)
+}
+
+/// Halves the dimensions of an image with a box filter, returning the new pixels and size.
+pub(crate) fn downsample(
+    pixels: &[u8],
+    width: u32,
+    height: u32,
+    filter: TexelFilter,
+    layout: TexelLayout,
+) -> (Vec<u8>, u32, u32) {
+    let (width, height) = (width as usize, height as usize);
+    let (half_width, half_height) = ((width / 2).max(1), (height / 2).max(1));
+    let texel_size = layout.texel_size();
+    let texel = |x: usize, y: usize| {
+        let offset = texel_size * (y.min(height - 1) * width + x.min(width - 1));
+        &pixels[offset..offset + texel_size]
+    };
+
+    let mut result = Vec::with_capacity(texel_size * half_width * half_height);
+    for y in 0..half_height {
+        for x in 0..half_width {
+            let block = [
+                texel(2 * x, 2 * y),
+                texel(2 * x + 1, 2 * y),
+                texel(2 * x, 2 * y + 1),
+                texel(2 * x + 1, 2 * y + 1),
+            ];
+            filter.average(layout, block, &mut result);
+        }
+    }
+    (result, half_width as u32, half_height as u32)
+}
+
+fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(value: f32) -> f32 {
+    if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bevy::render::{
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+        texture::Image,
+    };
+
+    use super::{downsample, generate_mipmaps, limit_size, TexelFilter, TexelLayout};
+
+    fn rgba8() -> TexelLayout {
+        TexelLayout::for_format(TextureFormat::Rgba8Unorm).unwrap()
+    }
+
+    fn image(width: u32, height: u32, format: TextureFormat, texel: &[u8]) -> Image {
+        Image::new_fill(
+            Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            texel,
+            format,
+        )
+    }
+
+    #[test]
+    fn mipmaps_full_chain() {
+        let mut image = image(8, 3, TextureFormat::Rgba8Unorm, &[10, 20, 30, 40]);
+        assert!(generate_mipmaps(&mut image, false));
+
+        // 8x3, 4x1, 2x1, 1x1
+        assert_eq!(image.texture_descriptor.mip_level_count, 4);
+        assert_eq!(image.data.len(), 4 * (24 + 4 + 2 + 1));
+        assert!(image.data.chunks(4).all(|texel| texel == [10, 20, 30, 40]));
+    }
+
+    #[test]
+    fn mipmaps_other_formats() {
+        let texel = 1000u16.to_ne_bytes();
+        let mut gray = image(4, 2, TextureFormat::R16Uint, &texel);
+        assert!(generate_mipmaps(&mut gray, false));
+        assert_eq!(gray.texture_descriptor.mip_level_count, 3);
+        assert_eq!(gray.data, texel.repeat(8 + 2 + 1));
+
+        // Normal maps with two channels are filtered linearly
+        let mut normal = image(2, 2, TextureFormat::Rg8Unorm, &[0, 255]);
+        normal.data[..2].copy_from_slice(&[255, 255]);
+        assert!(generate_mipmaps(&mut normal, true));
+        assert_eq!(normal.data[8..], [64, 255]);
+
+        let mut hdr = image(2, 1, TextureFormat::Rgba32Float, &[0; 16]);
+        hdr.data[..4].copy_from_slice(&2.0f32.to_ne_bytes());
+        assert!(generate_mipmaps(&mut hdr, false));
+        assert_eq!(hdr.data[32..36], 1.0f32.to_ne_bytes());
+    }
+
+    #[test]
+    fn mipmaps_skip_unsupported_format() {
+        let mut image = image(4, 4, TextureFormat::Rgba16Float, &[0; 8]);
+        let data_len = image.data.len();
+        assert!(!generate_mipmaps(&mut image, false));
+
+        assert_eq!(image.texture_descriptor.mip_level_count, 1);
+        assert_eq!(image.data.len(), data_len);
+    }
+
+    #[test]
+    fn limit_size_downsamples() {
+        let mut image = image(8, 4, TextureFormat::Rgba8UnormSrgb, &[1, 2, 3, 4]);
+
+        assert!(limit_size(&mut image, 3, false));
+        assert_eq!(image.texture_descriptor.size.width, 2);
+        assert_eq!(image.texture_descriptor.size.height, 1);
+        assert_eq!(image.data, [1, 2, 3, 4, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn limit_size_downsamples_other_formats() {
+        let texel = [1000u16, 2000, 3000, 65535].map(u16::to_ne_bytes).concat();
+        let mut color = image(4, 4, TextureFormat::Rgba16Uint, &texel);
+        assert!(limit_size(&mut color, 2, false));
+        assert_eq!(color.texture_descriptor.size.width, 2);
+        assert_eq!(color.data, texel.repeat(4));
+
+        let mut gray = image(8, 2, TextureFormat::R8Unorm, &[7]);
+        assert!(limit_size(&mut gray, 4, false));
+        assert_eq!(gray.texture_descriptor.size.height, 1);
+        assert_eq!(gray.data, [7; 4]);
+    }
+
+    #[test]
+    fn limit_size_drops_mip_levels() {
+        let mut image = image(8, 8, TextureFormat::Rgba8Unorm, &[1, 2, 3, 4]);
+        generate_mipmaps(&mut image, false);
+
+        assert!(limit_size(&mut image, 2, false));
+        assert_eq!(image.texture_descriptor.size.width, 2);
+        assert_eq!(image.texture_descriptor.mip_level_count, 2);
+        assert_eq!(image.data.len(), 4 * (4 + 1));
+    }
+
+    #[test]
+    fn limit_size_skips_unsupported_format() {
+        let mut image = image(4, 4, TextureFormat::Rgba16Float, &[0; 8]);
+
+        assert!(!limit_size(&mut image, 2, false));
+        assert_eq!(image.texture_descriptor.size.width, 4);
+    }
+
+    #[test]
+    fn downsample_srgb_averages_in_linear_space() {
+        let pixels = [[0, 0, 0, 0], [255, 255, 255, 255]].repeat(2).concat();
+        let (result, width, height) = downsample(&pixels, 2, 2, TexelFilter::Srgb, rgba8());
+
+        assert_eq!((width, height), (1, 1));
+        assert_eq!(result, [188, 188, 188, 128]);
+    }
+
+    #[test]
+    fn downsample_normal_map_renormalizes() {
+        // +X and +Z unit normals
+        let pixels = [[255, 128, 128, 255], [128, 128, 255, 255]]
+            .repeat(2)
+            .concat();
+        let (result, _, _) = downsample(&pixels, 2, 2, TexelFilter::NormalMap, rgba8());
+
+        let normal = result[..3]
+            .iter()
+            .map(|v| *v as f32 / 255.0 * 2.0 - 1.0)
+            .collect::<Vec<_>>();
+        let length = normal.iter().map(|v| v * v).sum::<f32>().sqrt();
+        assert!((length - 1.0).abs() < 0.02);
+        assert_eq!(result[0], result[2]);
+    }
+}
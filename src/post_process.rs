@@ -0,0 +1,35 @@
+use bevy::ecs::{entity::Entity, world::World};
+use bevy::utils::HashMap;
+
+/// Modifies the world of each scene loaded from a glTF file before it is made into a
+/// [`Scene`](bevy::scene::Scene), for example to add components, despawn nodes, or restructure
+/// the hierarchy.
+pub trait ScenePostProcessor: Send + Sync + 'static {
+    /// Processes the `world` built for `scene` of the glTF file `gltf`. `node_entities` maps the
+    /// indices of the nodes in the scene to the entities spawned for them.
+    fn process(
+        &self,
+        world: &mut World,
+        gltf: &gltf::Document,
+        scene: &gltf::Scene,
+        node_entities: &HashMap<usize, Entity>,
+    );
+}
+
+impl<F> ScenePostProcessor for F
+where
+    F: Fn(&mut World, &gltf::Document, &gltf::Scene, &HashMap<usize, Entity>)
+        + Send
+        + Sync
+        + 'static,
+{
+    fn process(
+        &self,
+        world: &mut World,
+        gltf: &gltf::Document,
+        scene: &gltf::Scene,
+        node_entities: &HashMap<usize, Entity>,
+    ) {
+        self(world, gltf, scene, node_entities)
+    }
+}
@@ -0,0 +1,104 @@
+use bevy::utils::HashMap;
+use std::path::{Path, PathBuf};
+
+/// The kind of external resource referred to by a URI in a glTF file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UriRole {
+    Buffer,
+    Image,
+}
+
+/// The location or contents of an external resource referred to by a glTF file.
+#[derive(Clone, Debug)]
+pub enum ResolvedUri {
+    /// A path read through the asset server, relative to the asset folder.
+    Path(PathBuf),
+    /// The contents of the resource.
+    Bytes(Vec<u8>),
+}
+
+/// Maps the URIs of external buffers and images in glTF files to their locations or contents.
+///
+/// Data URIs are decoded by the loader and never passed to a resolver.
+pub trait UriResolver: Send + Sync + 'static {
+    /// Resolves a percent-decoded `uri` which the glTF file at `gltf_path` uses for a resource
+    /// of the given `role`. Returns `None` to fall back to the default resolution, which treats
+    /// `file://` URIs and absolute paths as paths and other URIs as relative to the glTF file.
+    fn resolve(&self, uri: &str, gltf_path: &Path, role: UriRole) -> Option<ResolvedUri>;
+}
+
+impl<F> UriResolver for F
+where
+    F: Fn(&str, &Path, UriRole) -> Option<ResolvedUri> + Send + Sync + 'static,
+{
+    fn resolve(&self, uri: &str, gltf_path: &Path, role: UriRole) -> Option<ResolvedUri> {
+        self(uri, gltf_path, role)
+    }
+}
+
+/// A [`UriResolver`] which replaces the URIs listed in a table with asset paths.
+#[derive(Clone, Debug, Default)]
+pub struct UriRemapTable {
+    paths: HashMap<String, PathBuf>,
+}
+
+impl UriRemapTable {
+    /// Resolves `uri` to `path` wherever it appears.
+    pub fn with(mut self, uri: &str, path: impl Into<PathBuf>) -> Self {
+        self.paths.insert(uri.to_string(), path.into());
+        self
+    }
+}
+
+impl UriResolver for UriRemapTable {
+    fn resolve(&self, uri: &str, _gltf_path: &Path, _role: UriRole) -> Option<ResolvedUri> {
+        self.paths.get(uri).cloned().map(ResolvedUri::Path)
+    }
+}
+
+/// Returns the asset path of a `uri` in the glTF file at `gltf_path` if no resolver handles it.
+pub(crate) fn default_uri_path(uri: &str, gltf_path: &Path) -> PathBuf {
+    if let Some(path) = uri.strip_prefix("file://") {
+        PathBuf::from(path)
+    } else if Path::new(uri).is_absolute() {
+        PathBuf::from(uri)
+    } else {
+        gltf_path.parent().unwrap_or(Path::new("")).join(uri)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use super::{default_uri_path, ResolvedUri, UriRemapTable, UriResolver, UriRole};
+
+    #[test]
+    fn default_uri_paths() {
+        let gltf_path = Path::new("models/scene.gltf");
+        assert_eq!(
+            default_uri_path("textures/a.png", gltf_path),
+            PathBuf::from("models/textures/a.png")
+        );
+        assert_eq!(
+            default_uri_path("file:///data/a.bin", gltf_path),
+            PathBuf::from("/data/a.bin")
+        );
+        assert_eq!(
+            default_uri_path("/data/a.bin", gltf_path),
+            PathBuf::from("/data/a.bin")
+        );
+    }
+
+    #[test]
+    fn remap_table() {
+        let table = UriRemapTable::default().with("a.png", "shared/0123abcd");
+        let gltf_path = Path::new("scene.gltf");
+
+        assert!(matches!(
+            table.resolve("a.png", gltf_path, UriRole::Image),
+            Some(ResolvedUri::Path(path)) if path == Path::new("shared/0123abcd")
+        ));
+        assert!(table.resolve("b.png", gltf_path, UriRole::Image).is_none());
+    }
+}
@@ -0,0 +1,238 @@
+use bevy::render::{
+    mesh::{Indices, Mesh, VertexAttributeValues},
+    render_resource::PrimitiveTopology,
+};
+use bevy::utils::HashMap;
+
+use crate::{ATTRIBUTE_JOINT_INDEX_1, ATTRIBUTE_JOINT_WEIGHT_1};
+
+/// The most joints a [`SkinnedMesh`](bevy::render::mesh::skinning::SkinnedMesh) can have in
+/// Bevy's skinning shader.
+pub(crate) const MAX_JOINTS: usize = 256;
+
+/// A part of a skinned mesh which is influenced by fewer joints than the whole.
+pub(crate) struct MeshPartition {
+    /// The part of the mesh, with joint indices into `joints`.
+    pub(crate) mesh: Mesh,
+    /// The indices into the skin's joints of the joints influencing the part.
+    pub(crate) joints: Vec<u16>,
+}
+
+/// Splits a skinned mesh into parts each influenced by at most `max_joints` joints, keeping the
+/// points, lines or triangles of the mesh whole. Returns `None` if the mesh is a strip or fan, or
+/// a single point, line or triangle is influenced by too many joints.
+pub(crate) fn partition_skinned_mesh(mesh: &Mesh, max_joints: usize) -> Option<Vec<MeshPartition>> {
+    let element_size = match mesh.primitive_topology() {
+        PrimitiveTopology::PointList => 1,
+        PrimitiveTopology::LineList => 2,
+        PrimitiveTopology::TriangleList => 3,
+        PrimitiveTopology::LineStrip | PrimitiveTopology::TriangleStrip => return None,
+    };
+    let influences = [
+        (Mesh::ATTRIBUTE_JOINT_INDEX, Mesh::ATTRIBUTE_JOINT_WEIGHT),
+        (ATTRIBUTE_JOINT_INDEX_1, ATTRIBUTE_JOINT_WEIGHT_1),
+    ]
+    .into_iter()
+    .filter_map(|(joint_attribute, weight_attribute)| {
+        match (
+            mesh.attribute(joint_attribute),
+            mesh.attribute(weight_attribute),
+        ) {
+            (
+                Some(VertexAttributeValues::Uint16x4(joints)),
+                Some(VertexAttributeValues::Float32x4(weights)),
+            ) => Some((joints, weights)),
+            _ => None,
+        }
+    })
+    .collect::<Vec<_>>();
+    let vertex_joints = |vertex: usize| {
+        influences.iter().flat_map(move |(joints, weights)| {
+            joints[vertex]
+                .iter()
+                .zip(&weights[vertex])
+                .filter(|(_, &weight)| weight > 0.0)
+                .map(|(&joint, _)| joint)
+        })
+    };
+
+    let indices = match mesh.indices() {
+        Some(indices) => indices.iter().collect::<Vec<_>>(),
+        None => (0..mesh.count_vertices()).collect(),
+    };
+
+    // Adds elements to each part until the next would take it over the limit
+    let mut parts = vec![];
+    let mut part_joints = HashMap::<u16, u16>::new();
+    let mut part_indices = vec![];
+    for element in indices.chunks_exact(element_size) {
+        let mut element_joints = element
+            .iter()
+            .flat_map(|&vertex| vertex_joints(vertex))
+            .collect::<Vec<_>>();
+        element_joints.sort_unstable();
+        element_joints.dedup();
+        if element_joints.len() > max_joints {
+            return None;
+        }
+        let new_joints = element_joints
+            .iter()
+            .filter(|joint| !part_joints.contains_key(joint))
+            .count();
+        if part_joints.len() + new_joints > max_joints {
+            parts.push((
+                std::mem::take(&mut part_joints),
+                std::mem::take(&mut part_indices),
+            ));
+        }
+        for joint in element_joints {
+            let local_joint = part_joints.len() as u16;
+            part_joints.entry(joint).or_insert(local_joint);
+        }
+        part_indices.extend_from_slice(element);
+    }
+    if !part_indices.is_empty() {
+        parts.push((part_joints, part_indices));
+    }
+
+    Some(
+        parts
+            .into_iter()
+            .map(|(part_joints, part_indices)| build_partition(mesh, part_joints, &part_indices))
+            .collect(),
+    )
+}
+
+/// Copies the vertices of `mesh` used by `indices` into a new mesh, remapping their joint indices
+/// with `joints`.
+fn build_partition(mesh: &Mesh, joints: HashMap<u16, u16>, indices: &[usize]) -> MeshPartition {
+    let mut vertices = vec![];
+    let mut vertex_map = HashMap::<usize, u32>::new();
+    let indices = indices
+        .iter()
+        .map(|&vertex| {
+            *vertex_map.entry(vertex).or_insert_with(|| {
+                vertices.push(vertex);
+                vertices.len() as u32 - 1
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut partition = mesh.clone();
+    for (_, values) in partition.attributes_mut() {
+        *values = select_vertices(values, &vertices);
+    }
+    for (joint_attribute, weight_attribute) in [
+        (Mesh::ATTRIBUTE_JOINT_INDEX, Mesh::ATTRIBUTE_JOINT_WEIGHT),
+        (ATTRIBUTE_JOINT_INDEX_1, ATTRIBUTE_JOINT_WEIGHT_1),
+    ] {
+        let Some(VertexAttributeValues::Float32x4(weights)) =
+            partition.attribute(weight_attribute).cloned()
+        else {
+            continue;
+        };
+        if let Some(VertexAttributeValues::Uint16x4(vertex_joints)) =
+            partition.attribute_mut(joint_attribute)
+        {
+            for (vertex_joints, vertex_weights) in vertex_joints.iter_mut().zip(weights) {
+                for (joint, weight) in vertex_joints.iter_mut().zip(vertex_weights) {
+                    *joint = if weight > 0.0 { joints[&*joint] } else { 0 };
+                }
+            }
+        }
+    }
+    partition.set_indices(Some(if vertices.len() > u16::MAX as usize {
+        Indices::U32(indices)
+    } else {
+        Indices::U16(indices.into_iter().map(|index| index as u16).collect())
+    }));
+
+    let mut joints = joints.into_iter().collect::<Vec<_>>();
+    joints.sort_unstable_by_key(|&(_, local_joint)| local_joint);
+    MeshPartition {
+        mesh: partition,
+        joints: joints.into_iter().map(|(joint, _)| joint).collect(),
+    }
+}
+
+/// Returns the values of `vertices` in order.
+fn select_vertices(values: &VertexAttributeValues, vertices: &[usize]) -> VertexAttributeValues {
+    macro_rules! select {
+        ($($variant:ident),*) => {
+            match values {
+                $(VertexAttributeValues::$variant(values) => VertexAttributeValues::$variant(
+                    vertices.iter().map(|&vertex| values[vertex]).collect(),
+                ),)*
+            }
+        };
+    }
+    select!(
+        Float32, Sint32, Uint32, Float32x2, Sint32x2, Uint32x2, Float32x3, Sint32x3, Uint32x3,
+        Float32x4, Sint32x4, Uint32x4, Sint16x2, Snorm16x2, Uint16x2, Unorm16x2, Sint16x4,
+        Snorm16x4, Uint16x4, Unorm16x4, Sint8x2, Snorm8x2, Uint8x2, Unorm8x2, Sint8x4, Snorm8x4,
+        Uint8x4, Unorm8x4
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use bevy::render::{
+        mesh::{Indices, Mesh, VertexAttributeValues},
+        render_resource::PrimitiveTopology,
+    };
+
+    use super::partition_skinned_mesh;
+
+    /// Builds a mesh of separate triangles, each of whose vertices is influenced by one joint.
+    fn mesh(triangle_joints: &[[u16; 3]]) -> Mesh {
+        let joints = triangle_joints.iter().flatten().collect::<Vec<_>>();
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+        mesh.insert_attribute(
+            Mesh::ATTRIBUTE_POSITION,
+            (0..joints.len())
+                .map(|vertex| [vertex as f32, 0.0, 0.0])
+                .collect::<Vec<_>>(),
+        );
+        mesh.insert_attribute(
+            Mesh::ATTRIBUTE_JOINT_INDEX,
+            VertexAttributeValues::Uint16x4(
+                joints.iter().map(|&&joint| [joint, 0, 0, 0]).collect(),
+            ),
+        );
+        mesh.insert_attribute(
+            Mesh::ATTRIBUTE_JOINT_WEIGHT,
+            vec![[1.0, 0.0, 0.0, 0.0]; joints.len()],
+        );
+        mesh.set_indices(Some(Indices::U32((0..joints.len() as u32).collect())));
+        mesh
+    }
+
+    #[test]
+    fn partitions_within_joint_limit() {
+        let mesh = mesh(&[[0, 1, 2], [2, 3, 0], [4, 5, 6], [5, 6, 0]]);
+        let partitions = partition_skinned_mesh(&mesh, 4).unwrap();
+
+        assert_eq!(partitions.len(), 2);
+        assert_eq!(partitions[0].joints, vec![0, 1, 2, 3]);
+        assert_eq!(partitions[1].joints, vec![4, 5, 6, 0]);
+        let Some(VertexAttributeValues::Uint16x4(joints)) =
+            partitions[1].mesh.attribute(Mesh::ATTRIBUTE_JOINT_INDEX)
+        else {
+            panic!("partition has no joint indices");
+        };
+        let joints = joints.iter().map(|joints| joints[0]).collect::<Vec<_>>();
+        assert_eq!(joints, vec![0, 1, 2, 1, 2, 3]);
+        let Some(VertexAttributeValues::Float32x3(positions)) =
+            partitions[1].mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+        else {
+            panic!("partition has no positions");
+        };
+        assert_eq!(positions[0], [6.0, 0.0, 0.0]);
+        assert_eq!(partitions[1].mesh.indices().unwrap().len(), 6);
+    }
+
+    #[test]
+    fn partition_fails_when_triangle_exceeds_limit() {
+        assert!(partition_skinned_mesh(&mesh(&[[0, 1, 2]]), 2).is_none());
+    }
+}